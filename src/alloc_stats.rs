@@ -0,0 +1,175 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+/// Heap usage of one run of a part, counted on the thread that ran it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllocStats {
+    /// Bytes allocated in total, including memory that was freed again.
+    pub allocated: u64,
+    /// Number of allocations, reallocations included.
+    pub allocations: u64,
+    /// Most bytes that were allocated and not yet freed at any one time.
+    pub peak: u64,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} in {} allocs, peak {}",
+            Bytes(self.allocated),
+            self.allocations,
+            Bytes(self.peak)
+        )
+    }
+}
+
+/// A number of bytes, printed like `1.5 MiB`.
+pub struct Bytes(pub u64);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut size = self.0 as f64 / 1024_f64;
+        let mut unit = 0;
+        while size >= 1024_f64 && unit < UNITS.len() - 1 {
+            size /= 1024_f64;
+            unit += 1;
+        }
+        write!(f, "{:.1} {}", size, UNITS[unit])
+    }
+}
+
+/// Run `func` and count its allocations. `None` unless the `alloc-stats` feature is enabled.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let (result, stats) = counting::measure(func);
+        (result, Some(stats))
+    }
+    #[cfg(not(feature = "alloc-stats"))]
+    {
+        (func(), None)
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use super::AllocStats;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    /// Wraps the system allocator, counting what each thread allocates. Thread locals keep
+    /// parts running side by side with `--jobs` apart, and need no synchronization.
+    struct Counting;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    thread_local! {
+        // `const` thread locals without destructors never allocate themselves
+        static ALLOCATED: Cell<u64> = const { Cell::new(0) };
+        static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+        // memory freed by another thread than the one allocating it can make this negative
+        static LIVE: Cell<i64> = const { Cell::new(0) };
+        static PEAK: Cell<i64> = const { Cell::new(0) };
+    }
+
+    fn add(size: usize) {
+        ALLOCATED.with(|a| a.set(a.get() + size as u64));
+        ALLOCATIONS.with(|a| a.set(a.get() + 1));
+        let live = LIVE.with(|l| {
+            l.set(l.get() + size as i64);
+            l.get()
+        });
+        PEAK.with(|p| p.set(p.get().max(live)));
+    }
+
+    fn remove(size: usize) {
+        LIVE.with(|l| l.set(l.get() - size as i64));
+    }
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                add(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+            if !ptr.is_null() {
+                add(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) };
+            remove(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new_ptr.is_null() {
+                remove(layout.size());
+                add(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    pub(super) fn measure<T>(func: impl FnOnce() -> T) -> (T, AllocStats) {
+        let allocated = ALLOCATED.with(Cell::get);
+        let allocations = ALLOCATIONS.with(Cell::get);
+        let live = LIVE.with(Cell::get);
+        PEAK.with(|p| p.set(live));
+
+        let result = func();
+
+        let stats = AllocStats {
+            allocated: ALLOCATED.with(Cell::get) - allocated,
+            allocations: ALLOCATIONS.with(Cell::get) - allocations,
+            peak: (PEAK.with(Cell::get) - live).max(0) as u64,
+        };
+        (result, stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(Bytes(512).to_string(), "512 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(Bytes(3 * 1024 * 1024).to_string(), "3.0 MiB");
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn test_measure() {
+        let (sum, stats) = measure(|| {
+            let mut v = Vec::with_capacity(1000);
+            v.extend(0..1000_u64);
+            drop(Vec::<u64>::with_capacity(10));
+            v.iter().sum::<u64>()
+        });
+        let stats = stats.unwrap();
+
+        assert_eq!(sum, 499500);
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.allocated, 8080);
+        assert_eq!(stats.peak, 8080);
+    }
+}
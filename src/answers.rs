@@ -0,0 +1,471 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{ANSI_GREEN, ANSI_RED, ANSI_RESET};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+use toml_edit::{Array, Document, Item, Table, TableLike, Value};
+
+pub const MANIFEST_PATH: &str = "answers.toml";
+
+/// Written at the top of `answers.toml` on every save.
+const HEADER: &str = "\
+# Expected answers, checked by `cargo test --test answers` and recorded with `cargo answers`.
+#
+# Tables are keyed by profile (e.g. one per account), year and day. Inputs of
+# the `default` profile are read from `src/inputs/<year>/NN.txt`, those of any
+# other profile from `src/inputs/<year>/<profile>/NN.txt`. Answers are strings; leave out
+# the ones you don't know yet. `wrong_part_one` and `wrong_part_two` list
+# answers that were rejected, so they never get submitted twice.
+";
+
+/// Profile whose inputs live directly in `src/inputs/<year>`.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Expected answers for both parts of a day. Unknown answers are left out.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Expected {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong_part_one: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong_part_two: Vec<String>,
+}
+
+impl Expected {
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Answers that were rejected for `part`.
+    pub fn wrong(&self, part: u8) -> &[String] {
+        match part {
+            1 => &self.wrong_part_one,
+            2 => &self.wrong_part_two,
+            _ => &[],
+        }
+    }
+
+    pub fn set_part(&mut self, part: u8, answer: &str) {
+        match part {
+            1 => self.part_one = Some(answer.to_string()),
+            2 => self.part_two = Some(answer.to_string()),
+            _ => {}
+        }
+    }
+
+    /// Remember a rejected answer. Returns `false` if it was already known to be wrong.
+    pub fn add_wrong(&mut self, part: u8, answer: &str) -> bool {
+        let wrong = match part {
+            1 => &mut self.wrong_part_one,
+            2 => &mut self.wrong_part_two,
+            _ => return false,
+        };
+        if wrong.iter().any(|w| w == answer) {
+            return false;
+        }
+        wrong.push(answer.to_string());
+        true
+    }
+
+    pub fn verify(&self, part: u8, answer: &str) -> Verdict {
+        match self.part(part) {
+            Some(expected) if expected == answer => Verdict::Verified,
+            Some(_) => Verdict::Mismatch,
+            None if self.wrong(part).iter().any(|w| w == answer) => Verdict::Mismatch,
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+/// How an answer compares to what is known about a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Verdict {
+    /// Same as the known correct answer.
+    Verified,
+    /// Differs from the known correct answer, or was rejected before.
+    Mismatch,
+    /// Neither known to be correct nor to be wrong.
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Verified => write!(f, "{}✔{}", ANSI_GREEN, ANSI_RESET),
+            Verdict::Mismatch => write!(f, "{}✘{}", ANSI_RED, ANSI_RESET),
+            Verdict::Unknown => write!(f, "?"),
+        }
+    }
+}
+
+/// Contents of `answers.toml`: expected answers per profile, year and day, e.g.
+///
+/// ```toml
+/// [default.2022.01]
+/// part_one = "24000"
+/// part_two = "45000"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Manifest {
+    pub profiles: BTreeMap<String, BTreeMap<String, BTreeMap<String, Expected>>>,
+}
+
+/// One expected answer, for one part of a day of one profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Case<'a> {
+    pub profile: &'a str,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub expected: &'a str,
+}
+
+impl Manifest {
+    pub fn parse(contents: &str) -> io::Result<Self> {
+        toml::from_str(contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Writes the answers into the file, keeping its comments and layout.
+    /// A new file starts with the standard header.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => self.update(&contents)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                format!("{}\n{}", HEADER, self.update("")?)
+            }
+            Err(e) => return Err(e),
+        };
+        fs::write(path, contents)
+    }
+
+    /// `contents` edited in place to hold exactly these answers. Comments and
+    /// formatting are kept, except on values that change.
+    pub fn update(&self, contents: &str) -> io::Result<String> {
+        let mut document = contents
+            .parse::<Document>()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let profiles = document.as_table_mut();
+        retain_keys(profiles, &self.profiles);
+        for (profile, years) in self.profiles.iter() {
+            let years_table = child_table(profiles, profile, true);
+            retain_keys(years_table, years);
+            for (year, days) in years.iter() {
+                let days_table = child_table(years_table, year, true);
+                retain_keys(days_table, days);
+                for (day, expected) in days.iter() {
+                    let table = child_table(days_table, day, false);
+                    set_answer(table, "part_one", expected.part_one.as_deref());
+                    set_answer(table, "part_two", expected.part_two.as_deref());
+                    set_answers(table, "wrong_part_one", &expected.wrong_part_one);
+                    set_answers(table, "wrong_part_two", &expected.wrong_part_two);
+                }
+            }
+        }
+
+        Ok(document.to_string())
+    }
+
+    pub fn get(&self, profile: &str, year: u16, day: u8) -> Option<&Expected> {
+        self.profiles
+            .get(profile)?
+            .get(&year.to_string())?
+            .get(&format!("{:02}", day))
+    }
+
+    pub fn entry(&mut self, profile: &str, year: u16, day: u8) -> &mut Expected {
+        self.profiles
+            .entry(profile.to_string())
+            .or_default()
+            .entry(year.to_string())
+            .or_default()
+            .entry(format!("{:02}", day))
+            .or_default()
+    }
+
+    pub fn verify(&self, profile: &str, year: u16, day: u8, part: u8, answer: &str) -> Verdict {
+        self.get(profile, year, day)
+            .map_or(Verdict::Unknown, |expected| expected.verify(part, answer))
+    }
+
+    /// Every known answer, ordered by profile, year, day and part.
+    /// Years and days that are not numbers are ignored.
+    pub fn cases(&self) -> Vec<Case<'_>> {
+        let mut cases = Vec::new();
+        for (profile, years) in self.profiles.iter() {
+            let mut days = years
+                .iter()
+                .filter_map(|(year, days)| Some((year.parse::<u16>().ok()?, days)))
+                .flat_map(|(year, days)| {
+                    days.iter().filter_map(move |(day, expected)| {
+                        Some((year, day.parse::<u8>().ok()?, expected))
+                    })
+                })
+                .collect::<Vec<_>>();
+            days.sort_by_key(|(year, day, _)| (*year, *day));
+
+            for (year, day, expected) in days {
+                for part in 1..=2 {
+                    if let Some(answer) = expected.part(part) {
+                        cases.push(Case {
+                            profile,
+                            year,
+                            day,
+                            part,
+                            expected: answer,
+                        });
+                    }
+                }
+            }
+        }
+        cases
+    }
+}
+
+/// The table at `key` of `parent`, added if missing. Profiles and years are
+/// added as implicit tables, so a new day gets a single `[profile.year.day]` header.
+fn child_table<'a>(
+    parent: &'a mut dyn TableLike,
+    key: &str,
+    implicit: bool,
+) -> &'a mut dyn TableLike {
+    if !parent.get(key).is_some_and(Item::is_table_like) {
+        let mut table = Table::new();
+        table.set_implicit(implicit);
+        parent.insert(key, Item::Table(table));
+    }
+    parent
+        .get_mut(key)
+        .and_then(Item::as_table_like_mut)
+        .unwrap()
+}
+
+/// Removes the entries of `table` that are not in `keep`.
+fn retain_keys<V>(table: &mut dyn TableLike, keep: &BTreeMap<String, V>) {
+    let stale = table
+        .iter()
+        .map(|(key, _)| key.to_string())
+        .filter(|key| !keep.contains_key(key))
+        .collect::<Vec<_>>();
+    for key in stale {
+        table.remove(&key);
+    }
+}
+
+/// Replaces the value at `key`, keeping the comments around it.
+fn replace_value(table: &mut dyn TableLike, key: &str, value: Value) {
+    match table.get_mut(key).and_then(Item::as_value_mut) {
+        Some(old) => {
+            let decor = old.decor().clone();
+            *old = value;
+            *old.decor_mut() = decor;
+        }
+        None => {
+            table.insert(key, Item::Value(value));
+        }
+    }
+}
+
+/// A literal string like the ones `toml` writes, unless `answer` can't be one.
+fn string_value(answer: &str) -> Value {
+    if answer.chars().any(|c| c == '\'' || c.is_control()) {
+        answer.into()
+    } else {
+        format!("'{}'", answer).parse().unwrap()
+    }
+}
+
+fn set_answer(table: &mut dyn TableLike, key: &str, answer: Option<&str>) {
+    match answer {
+        None => {
+            table.remove(key);
+        }
+        Some(answer) if table.get(key).and_then(Item::as_str) == Some(answer) => {}
+        Some(answer) => replace_value(table, key, string_value(answer)),
+    }
+}
+
+fn set_answers(table: &mut dyn TableLike, key: &str, answers: &[String]) {
+    let current = table
+        .get(key)
+        .and_then(Item::as_array)
+        .map(|array| array.iter().map(Value::as_str).collect::<Vec<_>>());
+
+    if answers.is_empty() {
+        table.remove(key);
+    } else if current != Some(answers.iter().map(|a| Some(a.as_str())).collect()) {
+        let array = answers.iter().map(|a| string_value(a)).collect::<Array>();
+        replace_value(table, key, array.into());
+    }
+}
+
+/// Directory holding the `NN.txt` inputs of a profile for `year`.
+pub fn profile_dir(year: u16, profile: &str) -> PathBuf {
+    let inputs = crate::inputs_dir(year);
+    match profile {
+        DEFAULT_PROFILE => inputs,
+        _ => inputs.join(profile),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest() {
+        let manifest = Manifest::parse(
+            r#"
+            [default.2022.10]
+            part_one = "13140"
+
+            [default.2022.02]
+            part_one = "15"
+            part_two = "12"
+
+            [default.2021.25]
+            part_one = "1"
+
+            [alice.2022.02]
+            part_two = "8"
+            "#,
+        )
+        .unwrap();
+
+        let cases = manifest
+            .cases()
+            .into_iter()
+            .map(|c| (c.profile, c.year, c.day, c.part, c.expected))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            cases,
+            [
+                ("alice", 2022, 2, 2, "8"),
+                ("default", 2021, 25, 1, "1"),
+                ("default", 2022, 2, 1, "15"),
+                ("default", 2022, 2, 2, "12"),
+                ("default", 2022, 10, 1, "13140"),
+            ]
+        );
+
+        assert!(Manifest::parse("[default.2022.01]\npart_one = 5").is_err());
+        assert_eq!(
+            profile_dir(2022, "alice"),
+            profile_dir(2022, DEFAULT_PROFILE).join("alice")
+        );
+        assert_ne!(
+            profile_dir(2021, DEFAULT_PROFILE),
+            profile_dir(2022, DEFAULT_PROFILE)
+        );
+    }
+
+    #[test]
+    fn test_verify_and_save() {
+        let mut manifest = Manifest::default();
+        manifest.entry("alice", 2022, 1).set_part(1, "24000");
+        assert!(manifest.entry("alice", 2022, 1).add_wrong(2, "44000"));
+        assert!(!manifest.entry("alice", 2022, 1).add_wrong(2, "44000"));
+        manifest.entry("bob", 2022, 10).set_part(2, "#..#\n.##.");
+
+        assert_eq!(
+            manifest.verify("alice", 2022, 1, 1, "24000"),
+            Verdict::Verified
+        );
+        assert_eq!(
+            manifest.verify("alice", 2022, 1, 1, "24001"),
+            Verdict::Mismatch
+        );
+        assert_eq!(
+            manifest.verify("alice", 2022, 1, 2, "44000"),
+            Verdict::Mismatch
+        );
+        assert_eq!(
+            manifest.verify("alice", 2022, 1, 2, "45000"),
+            Verdict::Unknown
+        );
+        assert_eq!(
+            manifest.verify("carol", 2022, 1, 1, "24000"),
+            Verdict::Unknown
+        );
+
+        let path = std::env::temp_dir().join("aoc-answers-test.toml");
+        let _ = fs::remove_file(&path);
+        manifest.save(&path).unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(contents.starts_with("# Expected answers"));
+        assert_eq!(Manifest::parse(&contents).unwrap(), manifest);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_update_in_place() {
+        let contents = "\
+# my answers
+
+[default.2022.01]
+# from the first try
+part_one = '24000' # finally
+part_two = '45000'
+
+# still stuck on part two
+[default.2022.02]
+part_one = '15'
+";
+        let mut manifest = Manifest::parse(contents).unwrap();
+        assert_eq!(manifest.update(contents).unwrap(), contents);
+
+        manifest.entry("default", 2022, 1).set_part(1, "24001");
+        manifest.entry("default", 2022, 2).set_part(2, "12");
+        manifest.entry("default", 2022, 3).set_part(1, "157");
+        manifest.entry("alice", 2022, 1).add_wrong(2, "44000");
+        manifest.entry("bob", 2022, 10).set_part(2, "#..#\n.##.");
+        let updated = manifest.update(contents).unwrap();
+        assert_eq!(
+            updated,
+            "\
+# my answers
+
+[default.2022.01]
+# from the first try
+part_one = '24001' # finally
+part_two = '45000'
+
+# still stuck on part two
+[default.2022.02]
+part_one = '15'
+part_two = '12'
+
+[default.2022.03]
+part_one = '157'
+
+[alice.2022.01]
+wrong_part_two = ['44000']
+
+[bob.2022.10]
+part_two = \"\"\"
+#..#
+.##.\"\"\"
+"
+        );
+        assert_eq!(Manifest::parse(&updated).unwrap(), manifest);
+    }
+}
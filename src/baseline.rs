@@ -0,0 +1,233 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{PartResult, Status, ANSI_GREEN, ANSI_RED, ANSI_RESET};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::{fs, io};
+
+/// Days are only numbered within a year, so every year keeps its own baseline.
+pub fn default_path(year: u16) -> PathBuf {
+    format!("target/aoc-baseline-{}.json", year).into()
+}
+
+/// Slowdowns smaller than this are treated as noise, whatever the relative change.
+pub const NOISE_FLOOR: Duration = Duration::from_micros(10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    pub day: u8,
+    pub part: u8,
+    pub elapsed_ns: u64,
+}
+
+/// Per-part timings of a previous run.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub timings: Vec<Timing>,
+}
+
+impl Baseline {
+    pub fn from_results(results: &[PartResult]) -> Self {
+        Baseline {
+            timings: results
+                .iter()
+                .filter(|r| r.status == Status::Solved)
+                .map(|r| Timing {
+                    day: r.day,
+                    part: r.part,
+                    elapsed_ns: r.elapsed_ns,
+                })
+                .collect(),
+        }
+    }
+
+    /// Replace the timings of the parts that were solved in `results`,
+    /// keeping the ones for parts that were not run this time.
+    pub fn merge(&mut self, results: &[PartResult]) {
+        for timing in Baseline::from_results(results).timings {
+            match self
+                .timings
+                .iter_mut()
+                .find(|t| t.day == timing.day && t.part == timing.part)
+            {
+                Some(existing) => *existing = timing,
+                None => self.timings.push(timing),
+            }
+        }
+        self.timings.sort_by_key(|t| (t.day, t.part));
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        serde_json::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+
+    fn get(&self, day: u8, part: u8) -> Option<Duration> {
+        self.timings
+            .iter()
+            .find(|t| t.day == day && t.part == part)
+            .map(|t| Duration::from_nanos(t.elapsed_ns))
+    }
+
+    /// Compare the solved parts of a run against this baseline.
+    /// `threshold` is the relative slowdown (e.g. `0.1` for 10%) that counts as a regression.
+    pub fn compare(&self, results: &[PartResult], threshold: f64) -> Vec<Comparison> {
+        results
+            .iter()
+            .filter(|r| r.status == Status::Solved)
+            .filter_map(|r| {
+                let before = self.get(r.day, r.part)?;
+                let after = r.elapsed();
+                let change = after.as_secs_f64() / before.as_secs_f64().max(f64::EPSILON) - 1_f64;
+
+                Some(Comparison {
+                    day: r.day,
+                    part: r.part,
+                    before,
+                    after,
+                    change,
+                    regressed: change > threshold && after > before + NOISE_FLOOR,
+                    improved: -change > threshold && before > after + NOISE_FLOOR,
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub before: Duration,
+    pub after: Duration,
+    /// Relative change, e.g. `0.25` if the part got 25% slower.
+    pub change: f64,
+    pub regressed: bool,
+    pub improved: bool,
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let color = if self.regressed {
+            ANSI_RED
+        } else if self.improved {
+            ANSI_GREEN
+        } else {
+            ""
+        };
+
+        write!(
+            f,
+            "Day {:02} Part {}: {:.2?} -> {}{:.2?} ({:+.1}%){}",
+            self.day,
+            self.part,
+            self.before,
+            color,
+            self.after,
+            self.change * 100_f64,
+            ANSI_RESET
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(day: u8, part: u8, elapsed: Duration) -> PartResult {
+        PartResult {
+            year: 2022,
+            day,
+            part,
+            answer: Some("0".to_string()),
+            elapsed_ns: elapsed.as_nanos() as u64,
+            cpu_ns: None,
+            alloc: None,
+            status: Status::Solved,
+            error: None,
+            verdict: None,
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let ms = Duration::from_millis;
+        let baseline = Baseline::from_results(&[
+            result(1, 1, ms(10)),
+            result(1, 2, ms(10)),
+            result(2, 1, ms(10)),
+            result(2, 2, Duration::from_nanos(100)),
+        ]);
+
+        let comparisons = baseline.compare(
+            &[
+                result(1, 1, ms(12)),
+                result(1, 2, ms(8)),
+                result(2, 1, Duration::from_micros(10_500)),
+                result(2, 2, Duration::from_nanos(500)),
+                result(3, 1, ms(1)),
+            ],
+            0.1,
+        );
+
+        let flags = comparisons
+            .iter()
+            .map(|c| (c.day, c.part, c.regressed, c.improved))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            flags,
+            [
+                (1, 1, true, false),
+                (1, 2, false, true),
+                (2, 1, false, false),
+                // 5x slower, but only by a few hundred nanoseconds
+                (2, 2, false, false),
+            ]
+        );
+        assert!((comparisons[0].change - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_merge() {
+        let us = Duration::from_micros;
+        let mut baseline = Baseline::from_results(&[
+            result(1, 1, us(10)),
+            result(1, 2, us(20)),
+            result(3, 1, us(30)),
+        ]);
+
+        let mut unsolved = result(3, 1, us(1));
+        unsolved.status = Status::Unsolved;
+        baseline.merge(&[result(2, 1, us(5)), result(1, 2, us(15)), unsolved]);
+
+        let timings = baseline
+            .timings
+            .iter()
+            .map(|t| (t.day, t.part, t.elapsed_ns / 1000))
+            .collect::<Vec<_>>();
+        assert_eq!(timings, [(1, 1, 10), (1, 2, 15), (2, 1, 5), (3, 1, 30)]);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir().join(format!("aoc-baseline-{}.json", std::process::id()));
+        let baseline = Baseline::from_results(&[result(4, 2, Duration::from_micros(5))]);
+
+        baseline.save(&path).unwrap();
+        assert_eq!(Baseline::load(&path).unwrap(), baseline);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,94 @@
+#![feature(test)]
+#![feature(binary_heap_into_iter_sorted)]
+
+use advent_of_code::Error;
+
+/// Adds the ration on line `i` (0-based) to what the current elf carries.
+fn add_ration(current: u32, i: usize, line: &str) -> Result<u32, Error> {
+    line.parse::<u32>()
+        .ok()
+        .and_then(|ration| current.checked_add(ration))
+        .ok_or_else(|| Error::parse(i + 1, 1, format!("{:?} is not a number of calories", line)))
+}
+
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    let mut max: u32 = 0;
+    let mut current: u32 = 0;
+
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            if current > max {
+                max = current;
+            }
+            current = 0;
+        } else {
+            current = add_ration(current, i, line)?;
+        }
+    }
+    Ok(max.max(current))
+}
+
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    let mut heap = std::collections::BinaryHeap::new();
+    let mut current: u32 = 0;
+
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            heap.push(current);
+            current = 0;
+        } else {
+            current = add_ration(current, i, line)?;
+        }
+    }
+
+    heap.push(current);
+
+    Ok(heap.into_iter_sorted().take(3).sum())
+}
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 1);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate test;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 1).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 24000);
+        // the last elf counts without an empty line after it
+        assert_eq!(part_one("1\n\n2\n3").unwrap(), 5);
+
+        assert!(matches!(
+            part_one("1000\n\n20x0\n"),
+            Err(Error::Parse {
+                line: 3,
+                column: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 1).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 45000);
+    }
+
+    #[bench]
+    fn bench_part_one(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 1).unwrap();
+        b.iter(|| part_one(input));
+    }
+
+    #[bench]
+    fn bench_part_two(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 1).unwrap();
+        b.iter(|| part_two(input));
+    }
+}
@@ -0,0 +1,115 @@
+#![feature(test)]
+
+use advent_of_code::helpers::parse_lines;
+use advent_of_code::Error;
+
+fn play(p1: char, p2: char) -> u32 {
+    // shape score
+    let score = match p2 {
+        'X' => 1,
+        'Y' => 2,
+        'Z' => 3,
+        _ => 0,
+    };
+
+    // match outcome
+    score
+        + match (p1, p2) {
+            // draw
+            ('A', 'X') => 3,
+            ('B', 'Y') => 3,
+            ('C', 'Z') => 3,
+            // win
+            ('A', 'Y') => 6,
+            ('B', 'Z') => 6,
+            ('C', 'X') => 6,
+            // lose
+            _ => 0,
+        }
+}
+
+/// A line such as `A Y`: the opponent's move, and the second column of the strategy guide.
+fn parse_round(line: &str) -> Result<(char, char), Error> {
+    match line.as_bytes() {
+        [p1 @ b'A'..=b'C', b' ', p2 @ b'X'..=b'Z'] => Ok((*p1 as char, *p2 as char)),
+        [b'A'..=b'C', b' ', ..] => Err(Error::parse(1, 3, "expected X, Y or Z")),
+        _ => Err(Error::parse(1, 1, "expected A, B or C")),
+    }
+}
+
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    let rounds = parse_lines(input, parse_round)?;
+    Ok(rounds.into_iter().map(|(p1, p2)| play(p1, p2)).sum())
+}
+
+fn strategy(p1: char, outcome: char) -> u32 {
+    let my_move = match (p1, outcome) {
+        // lose
+        ('A', 'X') => 'Z',
+        ('B', 'X') => 'X',
+        ('C', 'X') => 'Y',
+        // draw
+        ('A', 'Y') => 'X',
+        ('B', 'Y') => 'Y',
+        ('C', 'Y') => 'Z',
+        // win
+        ('A', 'Z') => 'Y',
+        ('B', 'Z') => 'Z',
+        ('C', 'Z') => 'X',
+        _ => unreachable!(),
+    };
+
+    play(p1, my_move)
+}
+
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    let rounds = parse_lines(input, parse_round)?;
+    Ok(rounds
+        .into_iter()
+        .map(|(p1, outcome)| strategy(p1, outcome))
+        .sum())
+}
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 2);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate test;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 2).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 15);
+        assert!(matches!(
+            part_one("A Y\nB W"),
+            Err(Error::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 2).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 12);
+    }
+
+    #[bench]
+    fn bench_part_one(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 2).unwrap();
+        b.iter(|| part_one(input));
+    }
+
+    #[bench]
+    fn bench_part_two(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 2).unwrap();
+        b.iter(|| part_two(input));
+    }
+}
@@ -0,0 +1,126 @@
+#![feature(test)]
+#![feature(iter_array_chunks)]
+
+use advent_of_code::helpers::parse_lines;
+use advent_of_code::Error;
+
+fn priority(x: u8) -> u8 {
+    if x <= 90 {
+        x - 38
+    } else {
+        x - 96
+    }
+}
+
+type Set = u64;
+
+/// Items of a rucksack, which are letters, one bit per priority.
+fn set_of(rucksack: &str) -> Result<Set, Error> {
+    rucksack.char_indices().try_fold(0, |acc, (i, c)| {
+        if c.is_ascii_alphabetic() {
+            Ok(acc | (1 << priority(c as u8)))
+        } else {
+            Err(Error::parse(1, i + 1, format!("unexpected {:?}", c)))
+        }
+    })
+}
+
+/// Priority of the item in all of the sets, if there is one.
+fn set_intersects(s: &[Set]) -> Option<u32> {
+    let common = s.iter().fold(Set::MAX, |acc, &e| acc & e);
+    (common != 0).then(|| common.trailing_zeros())
+}
+
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    let priorities = parse_lines(input, |line| {
+        set_of(line)?;
+        if line.len() % 2 != 0 {
+            return Err(Error::parse(1, 1, "expected an even number of items"));
+        }
+        let (left, right) = line.split_at(line.len() / 2);
+        set_intersects(&[set_of(left)?, set_of(right)?])
+            .ok_or_else(|| Error::parse(1, 1, "no item is in both compartments"))
+    })?;
+    Ok(priorities.iter().sum())
+}
+
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    let sets = parse_lines(input, set_of)?;
+    if sets.len() % 3 != 0 {
+        return Err(Error::parse(
+            sets.len(),
+            1,
+            "expected groups of three rucksacks",
+        ));
+    }
+
+    sets.iter()
+        .array_chunks::<3>()
+        .enumerate()
+        .map(|(i, g)| {
+            set_intersects(&g.map(|&s| s))
+                .ok_or_else(|| Error::parse(3 * i + 1, 1, "no item is in all three rucksacks"))
+        })
+        .sum()
+}
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 3);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate test;
+
+    #[test]
+    fn test_priority() {
+        assert_eq!(priority(b'a'), 1);
+        assert_eq!(priority(b'z'), 26);
+        assert_eq!(priority(b'A'), 27);
+        assert_eq!(priority(b'Z'), 52);
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 3).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 157);
+
+        assert!(matches!(
+            part_one("abca\nab1b"),
+            Err(Error::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+        assert!(matches!(
+            part_one("abcd"),
+            Err(Error::Parse { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 3).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 70);
+        assert!(matches!(
+            part_two("ab\nbc"),
+            Err(Error::Parse { line: 2, .. })
+        ));
+    }
+
+    #[bench]
+    fn bench_part_one(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 3).unwrap();
+        b.iter(|| part_one(input));
+    }
+
+    #[bench]
+    fn bench_part_two(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 3).unwrap();
+        b.iter(|| part_two(input));
+    }
+}
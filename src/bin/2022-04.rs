@@ -0,0 +1,72 @@
+#![feature(test)]
+#![feature(iter_collect_into)]
+
+use advent_of_code::helpers::{parse_lines, parse_with_regex};
+use advent_of_code::Error;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref RE: Regex = Regex::new(r"^(\d+)-(\d+),(\d+)-(\d+)$").unwrap();
+}
+
+fn parse_pairs(input: &str) -> Result<Vec<[u32; 4]>, Error> {
+    parse_lines(input, |line| parse_with_regex::<u32, 4>(&RE, line))
+}
+
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    Ok(parse_pairs(input)?
+        .into_iter()
+        .filter(|&[a, b, c, d]| c >= a && d <= b || a >= c && b <= d)
+        .count() as u32)
+}
+
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    Ok(parse_pairs(input)?
+        .into_iter()
+        .filter(|&[a, b, c, d]| {
+            c >= a && d <= b || a >= c && b <= d || c <= a && a <= d || c <= b && d >= b
+        })
+        .count() as u32)
+}
+
+fn main() {
+    let _ = RE.is_match(""); // pre-load regex
+    let input = &advent_of_code::read_input(2022, 4);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate test;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 4).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 2);
+        assert!(matches!(
+            part_one("2-4,6-8\n2-3;4-5"),
+            Err(Error::Parse { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 4).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 4);
+    }
+
+    #[bench]
+    fn bench_part_one(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 4).unwrap();
+        b.iter(|| part_one(input));
+    }
+
+    #[bench]
+    fn bench_part_two(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 4).unwrap();
+        b.iter(|| part_two(input));
+    }
+}
@@ -0,0 +1,130 @@
+#![feature(test)]
+
+use advent_of_code::helpers::{disjoint_mut_refs, parse_with_regex};
+use advent_of_code::Error;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref RE: Regex = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+}
+
+fn parse_crates(input: &[&str]) -> Vec<Vec<char>> {
+    let last = *input.last().unwrap();
+    let count = last.len() / 4 + 1;
+
+    let mut stacks = vec![Vec::new(); count];
+
+    for line in input.iter().rev().skip(1) {
+        for (i, c) in line.chars().skip(1).step_by(4).enumerate() {
+            if c != ' ' {
+                stacks[i].push(c);
+            }
+        }
+    }
+
+    stacks
+}
+
+/// The stacks and the moves as `[count, from, to]`, with the stacks counted from 0.
+type Input = (Vec<Vec<char>>, Vec<[usize; 3]>);
+
+fn parse_input(input: &str) -> Result<Input, Error> {
+    let mut lines = input.lines();
+
+    let stacks: Vec<&str> = lines.by_ref().take_while(|x| !x.is_empty()).collect();
+    if stacks.is_empty() {
+        return Err(Error::parse(1, 1, "expected a drawing of the stacks"));
+    }
+    let first_move = stacks.len() + 2;
+    let stacks = parse_crates(&stacks);
+
+    let mut moves = Vec::new();
+    for (i, line) in lines.enumerate() {
+        let [num, from, to] =
+            parse_with_regex::<usize, 3>(&RE, line).map_err(|e| e.on_line(first_move + i))?;
+
+        let exists = |stack| (1..=stacks.len()).contains(&stack);
+        if from == to || !exists(from) || !exists(to) {
+            return Err(Error::parse(
+                first_move + i,
+                1,
+                format!("cannot move from stack {} to stack {}", from, to),
+            ));
+        }
+        moves.push([num, from - 1, to - 1]);
+    }
+
+    Ok((stacks, moves))
+}
+
+pub fn part_one(input: &str) -> Result<String, Error> {
+    let (mut stacks, moves) = parse_input(input)?;
+
+    for [num, from, to] in moves {
+        let (source, target) = disjoint_mut_refs(&mut stacks, from, to);
+
+        for elem in source.drain(source.len() - num..).rev() {
+            target.push(elem);
+        }
+    }
+
+    Ok(stacks.iter().filter_map(|s| s.last()).collect())
+}
+
+pub fn part_two(input: &str) -> Result<String, Error> {
+    let (mut stacks, moves) = parse_input(input)?;
+
+    for [num, from, to] in moves {
+        let (source, target) = disjoint_mut_refs(&mut stacks, from, to);
+
+        for elem in source.drain(source.len() - num..) {
+            target.push(elem);
+        }
+    }
+
+    Ok(stacks.iter().filter_map(|s| s.last()).collect())
+}
+
+fn main() {
+    let _ = RE.is_match(""); // pre-load regex
+    let input = &advent_of_code::read_input(2022, 5);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate test;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 5).unwrap();
+        assert_eq!(part_one(&input).unwrap(), "CMZ");
+
+        let input = input.replace("from 1 to 3", "from 1 to 4");
+        assert!(matches!(
+            part_one(&input),
+            Err(Error::Parse { line: 7, .. })
+        ));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 5).unwrap();
+        assert_eq!(part_two(&input).unwrap(), "MCD");
+    }
+
+    #[bench]
+    fn bench_part_one(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 5).unwrap();
+        b.iter(|| part_one(input));
+    }
+
+    #[bench]
+    fn bench_part_two(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 5).unwrap();
+        b.iter(|| part_two(input));
+    }
+}
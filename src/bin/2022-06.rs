@@ -0,0 +1,107 @@
+#![feature(test)]
+
+use advent_of_code::Error;
+
+fn encode(x: u8) -> u64 {
+    1 << (x - 97)
+}
+
+fn solve_n<const N: usize>(input: &str) -> Result<usize, Error> {
+    let signal = input.trim_end();
+    if let Some((i, c)) = signal
+        .char_indices()
+        .find(|&(_, c)| !c.is_ascii_lowercase())
+    {
+        return Err(Error::parse_at(input, i, format!("unexpected {:?}", c)));
+    }
+    let no_marker = || {
+        let message = format!("no {} different characters in a row", N);
+        Err(Error::parse_at(input, signal.len(), message))
+    };
+    if signal.len() < N {
+        return no_marker();
+    }
+
+    let mut buf: [u64; N] = [0; N];
+
+    let (left, right) = signal.split_at(N - 1);
+
+    for (i, c) in left.bytes().enumerate() {
+        buf[i] = encode(c);
+    }
+
+    let mut ptr = N - 1;
+
+    for (ix, c) in right.bytes().enumerate() {
+        buf[ptr] = encode(c);
+
+        if buf.iter().fold(0, |acc, &x| acc | x).count_ones() == N as u32 {
+            return Ok(ix + N);
+        }
+
+        ptr = (ptr + 1) % N;
+    }
+
+    no_marker()
+}
+
+pub fn part_one(input: &str) -> Result<usize, Error> {
+    solve_n::<4>(input)
+}
+
+pub fn part_two(input: &str) -> Result<usize, Error> {
+    solve_n::<14>(input)
+}
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 6);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate test;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 6).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 11);
+
+        assert!(matches!(
+            part_one("abcA"),
+            Err(Error::Parse {
+                line: 1,
+                column: 4,
+                ..
+            })
+        ));
+        assert!(matches!(
+            part_one("aab\n"),
+            Err(Error::Parse {
+                line: 1,
+                column: 4,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 6).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 26);
+    }
+
+    #[bench]
+    fn bench_part_one(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 6).unwrap();
+        b.iter(|| part_one(input));
+    }
+
+    #[bench]
+    fn bench_part_two(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 6).unwrap();
+        b.iter(|| part_two(input));
+    }
+}
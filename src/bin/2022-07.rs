@@ -0,0 +1,117 @@
+#![feature(test)]
+
+use advent_of_code::Error;
+
+fn process<F>(input: &str, init: u32, update: F) -> Result<u32, Error>
+where
+    F: Fn(u32, u32) -> u32,
+{
+    let mut stack: Vec<u32> = Vec::new();
+    let mut acc = init;
+
+    for (row, line) in input.lines().enumerate() {
+        let outside = || Error::parse(row + 1, 1, "outside of the outermost directory");
+
+        if line.starts_with('$') {
+            // parse command
+            let line = line.get(2..).unwrap_or_default();
+            if line == "cd .." {
+                // add to current directory
+                let size = stack.pop().ok_or_else(outside)?;
+                // update the accumulator
+                acc = update(acc, size);
+                // add to the outer directory
+                *stack.last_mut().ok_or_else(outside)? += size;
+            } else if line.len() > 2 {
+                // start counting in a new directory
+                stack.push(0);
+            }
+        } else if !line.starts_with('d') {
+            // count file size towards current directory
+            let (digits, _) = line.split_once(' ').unwrap_or((line, ""));
+            let size = digits.parse::<u32>().map_err(|_| {
+                Error::parse(row + 1, 1, format!("{:?} is not a file size", digits))
+            })?;
+            *stack.last_mut().ok_or_else(outside)? += size;
+        }
+    }
+
+    // remember to process remaining directories
+    while let Some(size) = stack.pop() {
+        acc = update(acc, size);
+
+        if let Some(last) = stack.last_mut() {
+            *last += size;
+        }
+    }
+
+    Ok(acc)
+}
+
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    process(
+        input,
+        0,
+        |acc, size| {
+            if size <= 100000 {
+                acc + size
+            } else {
+                acc
+            }
+        },
+    )
+}
+
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    let root_size = process(input, 0, |_, x| x)?;
+
+    let space_left = 70000000 - root_size;
+
+    process(input, root_size, |acc, size| {
+        if size < acc && space_left + size >= 30000000 {
+            size
+        } else {
+            acc
+        }
+    })
+}
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 7);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate test;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 7).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 95437);
+        assert!(matches!(
+            part_one("$ cd /\n$ ls\nx b.txt"),
+            Err(Error::Parse { line: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 7).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 24933642);
+    }
+
+    #[bench]
+    fn bench_part_one(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 7).unwrap();
+        b.iter(|| part_one(input));
+    }
+
+    #[bench]
+    fn bench_part_two(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 7).unwrap();
+        b.iter(|| part_two(input));
+    }
+}
@@ -1,7 +1,37 @@
 #![feature(test)]
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let side = input.lines().next().unwrap().len();
+use advent_of_code::Error;
+
+/// Side of the square of tree heights in `input`.
+fn parse_side(input: &str) -> Result<usize, Error> {
+    let side = input.lines().next().map_or(0, str::len);
+
+    for (y, line) in input.lines().enumerate() {
+        if let Some(x) = line.bytes().position(|c| !c.is_ascii_digit()) {
+            return Err(Error::parse(
+                y + 1,
+                x + 1,
+                "expected a tree height from 0 to 9",
+            ));
+        }
+        if line.len() != side {
+            return Err(Error::parse(y + 1, 1, format!("expected {} trees", side)));
+        }
+    }
+
+    let rows = input.lines().count();
+    if rows != side {
+        return Err(Error::parse(
+            rows.min(side) + 1,
+            1,
+            format!("expected {0} rows for a square of {0}x{0} trees", side),
+        ));
+    }
+    Ok(side)
+}
+
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    let side = parse_side(input)?;
     let mut bitmap = vec![0; side * side];
 
     for (y, line) in input.lines().enumerate() {
@@ -56,11 +86,11 @@ pub fn part_one(input: &str) -> Option<u32> {
         }
     }
 
-    Some(bitmap.iter().sum())
+    Ok(bitmap.iter().sum())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let side = input.lines().next().unwrap().len();
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    let side = parse_side(input)?;
     let mut scores = vec![(0, 0, 0, 0); side * side];
 
     let empty_seen = [0_usize; 10];
@@ -105,11 +135,15 @@ pub fn part_two(input: &str) -> Option<u32> {
         }
     }
 
-    scores.iter().map(|(a, b, c, d)| a * b * c * d).max()
+    scores
+        .iter()
+        .map(|(a, b, c, d)| a * b * c * d)
+        .max()
+        .ok_or(Error::Unsolved)
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 8);
+    let input = &advent_of_code::read_input(2022, 8);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -121,32 +155,37 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 8);
-        assert_eq!(part_one(&input), Some(21));
+        let input = advent_of_code::read_file("examples", 2022, 8).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 21);
+        assert!(matches!(
+            part_one("303\n2x5\n653"),
+            Err(Error::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+        assert!(matches!(
+            part_one("303\n255"),
+            Err(Error::Parse { line: 3, .. })
+        ));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 8);
-        assert_eq!(part_two(&input), Some(8));
-    }
-
-    #[test]
-    fn test_solution() {
-        let input = advent_of_code::read_file("inputs", 8);
-        assert_eq!(part_one(&input), Some(1843));
-        assert_eq!(part_two(&input), Some(180000));
+        let input = advent_of_code::read_file("examples", 2022, 8).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 8);
     }
 
     #[bench]
     fn bench_part_one(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 8);
+        let input = &advent_of_code::read_file("inputs", 2022, 8).unwrap();
         b.iter(|| part_one(input));
     }
 
     #[bench]
     fn bench_part_two(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 8);
+        let input = &advent_of_code::read_file("inputs", 2022, 8).unwrap();
         b.iter(|| part_two(input));
     }
 }
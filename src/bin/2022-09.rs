@@ -1,6 +1,7 @@
 #![feature(test)]
 
-use advent_of_code::helpers::disjoint_mut_refs;
+use advent_of_code::helpers::{disjoint_mut_refs, parse_lines};
+use advent_of_code::Error;
 use std::collections::hash_set::*;
 
 type Point = (i32, i32);
@@ -23,6 +24,18 @@ fn move_head(command: char, head: &mut Point) {
     }
 }
 
+/// A line such as `R 4`: the direction to move the head in, and how many steps.
+fn parse_motion(line: &str) -> Result<(char, u32), Error> {
+    let (command, steps) = match line.split_once(' ') {
+        Some((command @ ("U" | "D" | "L" | "R"), steps)) => (command, steps),
+        _ => return Err(Error::parse(1, 1, "expected U, D, L or R")),
+    };
+    let steps = steps
+        .parse()
+        .map_err(|_| Error::parse(1, 3, format!("{:?} is not a number of steps", steps)))?;
+    Ok((command.chars().next().unwrap(), steps))
+}
+
 fn move_next_knot(head: &Point, tail: &mut Point) {
     let dx = head.0 - tail.0;
     let dy = head.1 - tail.1;
@@ -36,17 +49,14 @@ fn move_next_knot(head: &Point, tail: &mut Point) {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &str) -> Result<u32, Error> {
     let mut head: Point = (0, 0);
     let mut tail: Point = (0, 0);
 
     let mut seen = HashSet::new();
     seen.insert(tail);
 
-    for line in input.lines() {
-        let command = line.chars().next().unwrap();
-        let steps = line.split_at(2).1.parse().unwrap();
-
+    for (command, steps) in parse_lines(input, parse_motion)? {
         for _ in 0..steps {
             move_head(command, &mut head);
             move_next_knot(&head, &mut tail);
@@ -56,19 +66,16 @@ pub fn part_one(input: &str) -> Option<u32> {
         }
     }
 
-    Some(seen.len() as u32)
+    Ok(seen.len() as u32)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Result<u32, Error> {
     let mut knots = [(0_i32, 0_i32); 10];
 
     let mut seen = HashSet::new();
     seen.insert((0, 0));
 
-    for line in input.lines() {
-        let command = line.chars().next().unwrap();
-        let steps = line.split_at(2).1.parse().unwrap();
-
+    for (command, steps) in parse_lines(input, parse_motion)? {
         for _ in 0..steps {
             // move head
             move_head(command, &mut knots[0]);
@@ -83,11 +90,11 @@ pub fn part_two(input: &str) -> Option<u32> {
         }
     }
 
-    Some(seen.len() as u32)
+    Ok(seen.len() as u32)
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 9);
+    let input = &advent_of_code::read_input(2022, 9);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -99,32 +106,33 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 9);
-        assert_eq!(part_one(&input), Some(13));
+        let input = advent_of_code::read_file("examples", 2022, 9).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 13);
+        assert!(matches!(
+            part_one("R 4\nU x"),
+            Err(Error::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 9);
-        assert_eq!(part_two(&input), Some(1));
-    }
-
-    #[test]
-    fn test_solution() {
-        let input = advent_of_code::read_file("inputs", 9);
-        assert_eq!(part_one(&input), Some(6339));
-        assert_eq!(part_two(&input), Some(2541));
+        let input = advent_of_code::read_file("examples", 2022, 9).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 1);
     }
 
     #[bench]
     fn bench_part_one(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 9);
+        let input = &advent_of_code::read_file("inputs", 2022, 9).unwrap();
         b.iter(|| part_one(input));
     }
 
     #[bench]
     fn bench_part_two(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 9);
+        let input = &advent_of_code::read_file("inputs", 2022, 9).unwrap();
         b.iter(|| part_two(input));
     }
 }
@@ -1,39 +1,52 @@
 #![feature(test)]
 #![feature(iter_intersperse)]
 
+use advent_of_code::helpers::parse_lines;
+use advent_of_code::Error;
+
 const WIDTH: usize = 40;
 const HEIGHT: usize = 6;
 const SIZE: usize = WIDTH * HEIGHT;
 
+/// `None` for `noop`, and the value of `addx <value>`.
+fn parse_instruction(line: &str) -> Result<Option<i32>, Error> {
+    match line.split_once(' ') {
+        None if line == "noop" => Ok(None),
+        Some(("addx", value)) => value
+            .parse()
+            .map(Some)
+            .map_err(|_| Error::parse(1, 6, format!("{:?} is not a number", value))),
+        _ => Err(Error::parse(1, 1, "expected noop or addx")),
+    }
+}
+
 const LIT: char = '#';
 const DARK: char = '.';
 
-pub fn part_one(input: &str) -> Option<i32> {
+pub fn part_one(input: &str) -> Result<i32, Error> {
     let mut cycle: i32 = 1;
     let mut register: i32 = 1;
     let mut signal: i32 = 0;
 
-    for line in input.lines() {
-        let (_, value) = line.split_at(4);
-
+    for value in parse_lines(input, parse_instruction)? {
         if (cycle - 20) % 40 == 0 {
             signal += cycle * register;
         }
         cycle += 1;
 
-        if !value.is_empty() {
+        if let Some(value) = value {
             if (cycle - 20) % 40 == 0 {
                 signal += cycle * register;
             }
             cycle += 1;
-            register += value[1..].parse::<i32>().unwrap();
+            register += value;
         }
     }
 
-    Some(signal)
+    Ok(signal)
 }
 
-pub fn part_two(input: &str) -> Option<String> {
+pub fn part_two(input: &str) -> Result<String, Error> {
     let mut cycle: i32 = 1;
     let mut register: i32 = 1;
     let mut display = [DARK; SIZE];
@@ -51,28 +64,24 @@ pub fn part_two(input: &str) -> Option<String> {
         cycle += 1;
     };
 
-    for line in input.lines() {
-        let (_, value) = line.split_at(4);
-
+    for value in parse_lines(input, parse_instruction)? {
         render(register);
 
-        if !value.is_empty() {
+        if let Some(value) = value {
             render(register);
-            register += value[1..].parse::<i32>().unwrap();
+            register += value;
         }
     }
 
-    Some(
-        display
-            .chunks(WIDTH)
-            .intersperse(&['\n'])
-            .flatten()
-            .collect(),
-    )
+    Ok(display
+        .chunks(WIDTH)
+        .intersperse(&['\n'])
+        .flatten()
+        .collect())
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
+    let input = &advent_of_code::read_input(2022, 10);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -84,54 +93,41 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_one(&input), Some(13140));
+        let input = advent_of_code::read_file("examples", 2022, 10).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 13140);
+        assert!(matches!(
+            part_one("noop\naddx -x"),
+            Err(Error::Parse {
+                line: 2,
+                column: 6,
+                ..
+            })
+        ));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = advent_of_code::read_file("examples", 2022, 10).unwrap();
         assert_eq!(
-            part_two(&input),
-            Some(
-                "##..##..##..##..##..##..##..##..##..##..
+            part_two(&input).unwrap(),
+            "##..##..##..##..##..##..##..##..##..##..
 ###...###...###...###...###...###...###.
 ####....####....####....####....####....
 #####.....#####.....#####.....#####.....
 ######......######......######......####
 #######.......#######.......#######....."
-                    .to_string()
-            )
-        );
-    }
-
-    #[test]
-    fn test_solution() {
-        let input = advent_of_code::read_file("inputs", 10);
-        assert_eq!(part_one(&input), Some(14240));
-        assert_eq!(
-            part_two(&input),
-            Some(
-                "###..#....#..#.#....#..#.###..####.#..#.
-...#.#....#..#.#....#.#..#..#....#.#..#.
-#..#.#....#..#.#....##...###....#..####.
-###..#....#..#.#....#.#..#..#..#...#..#.
-.....#....#..#.#....#.#..#..#.#....#..#.
-.....####..##..####.#..#.###..####.#..#."
-                    .to_string()
-            )
         );
     }
 
     #[bench]
     fn bench_part_one(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 10);
+        let input = &advent_of_code::read_file("inputs", 2022, 10).unwrap();
         b.iter(|| part_one(input));
     }
 
     #[bench]
     fn bench_part_two(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 10);
+        let input = &advent_of_code::read_file("inputs", 2022, 10).unwrap();
         b.iter(|| part_two(input));
     }
 }
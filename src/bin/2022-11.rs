@@ -1,6 +1,7 @@
 #![feature(test)]
 
 use advent_of_code::helpers::disjoint_mut_refs_3;
+use advent_of_code::Error;
 use lazy_static::lazy_static;
 use regex::Regex;
 
@@ -32,12 +33,36 @@ fn unchecked_push_vec<T>(vec: &mut Vec<T>, value: T) {
     }
 }
 
-fn parse_input(input: &str) -> Vec<Monkey> {
+fn parse_input(input: &str) -> Result<Vec<Monkey>, Error> {
     let mut monkeys = Vec::new();
     let mut capacity = 0;
+    // where each monkey's targets are, to check that they exist
+    let mut targets = Vec::new();
+    let mut end = 0;
 
     for cap in RE.captures_iter(input) {
-        let items: Vec<u64> = cap[1].split(", ").map(|s| s.parse().unwrap()).collect();
+        // every monkey has to match, text in between would be skipped silently
+        let skipped = &input[end..cap.get(0).unwrap().start()];
+        if !skipped.trim().is_empty() {
+            let offset = end + skipped.len() - skipped.trim_start().len();
+            return Err(Error::parse_at(input, offset, "expected a monkey"));
+        }
+        end = cap.get(0).unwrap().end();
+
+        let number = |group: usize| {
+            let m = cap.get(group).unwrap();
+            m.as_str().parse().map_err(|_| {
+                Error::parse_at(input, m.start(), format!("{} is too large", m.as_str()))
+            })
+        };
+
+        let items = cap[1]
+            .split(", ")
+            .map(|s| s.parse())
+            .collect::<Result<Vec<u64>, _>>()
+            .map_err(|_| {
+                Error::parse_at(input, cap.get(1).unwrap().start(), "an item is too large")
+            })?;
         capacity += items.len();
 
         let op = cap[3].chars().next().unwrap();
@@ -48,9 +73,13 @@ fn parse_input(input: &str) -> Vec<Monkey> {
             None
         };
 
-        let divisor = cap[5].parse().unwrap();
-        let true_monkey = cap[6].parse().unwrap();
-        let false_monkey = cap[7].parse().unwrap();
+        let divisor = number(5)?;
+        let true_monkey = number(6)? as usize;
+        let false_monkey = number(7)? as usize;
+        targets.push([
+            (true_monkey, cap.get(6).unwrap()),
+            (false_monkey, cap.get(7).unwrap()),
+        ]);
 
         monkeys.push(Monkey {
             items,
@@ -62,16 +91,34 @@ fn parse_input(input: &str) -> Vec<Monkey> {
         });
     }
 
+    if !input[end..].trim().is_empty() {
+        let offset = input.len() - input[end..].trim_start().len();
+        return Err(Error::parse_at(input, offset, "expected a monkey"));
+    }
+
+    // monkeys throw to others only, which are taken out of the list mutably at the same time
+    for (i, [(a, m), (b, n)]) in targets.iter().enumerate() {
+        for (target, m) in [(a, m), (b, n)] {
+            if *target == i || *target >= monkeys.len() {
+                return Err(Error::parse_at(
+                    input,
+                    m.start(),
+                    format!("monkey {} cannot throw to monkey {}", i, target),
+                ));
+            }
+        }
+    }
+
     // ensures that all monkeys have enough capacity to hold all items
     for monkey in &mut monkeys {
         monkey.items.reserve(capacity);
     }
 
-    monkeys
+    Ok(monkeys)
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let mut monkeys = parse_input(input);
+pub fn part_one(input: &str) -> Result<usize, Error> {
+    let mut monkeys = parse_input(input)?;
     let mut inspections = vec![0_usize; monkeys.len()];
 
     for _ in 0..20 {
@@ -109,7 +156,7 @@ pub fn part_one(input: &str) -> Option<usize> {
     }
 
     inspections.sort_unstable_by(|a, b| b.cmp(a));
-    Some(inspections[0] * inspections[1])
+    Ok(inspections[0] * inspections[1])
 }
 
 // 1,273,514 ns/iter (+/- 36,504) -- push_within_capacity
@@ -117,8 +164,8 @@ pub fn part_one(input: &str) -> Option<usize> {
 // 1,066,482 ns/iter (+/- 44,500) -- disjoint_mut_refs_3 without assertions
 // 1,054,114 ns/iter (+/- 68,945) -- Option instead of Operand
 // 1,042,988 ns/iter (+/- 70,647) -- non-constant items capacity
-pub fn part_two(input: &str) -> Option<usize> {
-    let mut monkeys = parse_input(input);
+pub fn part_two(input: &str) -> Result<usize, Error> {
+    let mut monkeys = parse_input(input)?;
     let mut inspections = vec![0_usize; monkeys.len()];
 
     let max_mod: u64 = monkeys.iter().map(|m| m.divisor).product();
@@ -156,11 +203,11 @@ pub fn part_two(input: &str) -> Option<usize> {
     }
 
     inspections.sort_unstable_by(|a, b| b.cmp(a));
-    Some(inspections[0] * inspections[1])
+    Ok(inspections[0] * inspections[1])
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 11);
+    let input = &advent_of_code::read_input(2022, 11);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -172,32 +219,35 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 11);
-        assert_eq!(part_one(&input), Some(10605));
+        let input = advent_of_code::read_file("examples", 2022, 11).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 10605);
+
+        let input = input.replace("throw to monkey 3", "throw to monkey 4");
+        assert!(matches!(
+            part_one(&input),
+            Err(Error::Parse {
+                line: 6,
+                column: 31,
+                ..
+            })
+        ));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 11);
-        assert_eq!(part_two(&input), Some(2713310158));
-    }
-
-    #[test]
-    fn test_solution() {
-        let input = advent_of_code::read_file("inputs", 11);
-        assert_eq!(part_one(&input), Some(54253));
-        assert_eq!(part_two(&input), Some(13119526120));
+        let input = advent_of_code::read_file("examples", 2022, 11).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 2713310158);
     }
 
     #[bench]
     fn bench_part_one(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 11);
+        let input = &advent_of_code::read_file("inputs", 2022, 11).unwrap();
         b.iter(|| part_one(input));
     }
 
     #[bench]
     fn bench_part_two(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 11);
+        let input = &advent_of_code::read_file("inputs", 2022, 11).unwrap();
         b.iter(|| part_two(input));
     }
 }
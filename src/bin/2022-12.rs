@@ -1,6 +1,8 @@
 #![feature(test)]
 
 extern crate bucket_queue;
+use advent_of_code::helpers::Grid;
+use advent_of_code::Error;
 use bucket_queue::*;
 use std::collections::HashMap;
 
@@ -32,65 +34,52 @@ impl Graph {
     }
 }
 
-fn parse_graph_from_grid_of_adjacent_chars(input: &str, reverse: bool) -> Graph {
-    let mut nodes = Vec::new();
-    let mut edges = HashMap::new();
+fn parse_graph_from_grid_of_adjacent_chars(input: &str, reverse: bool) -> Result<Graph, Error> {
+    let grid = Grid::parse(input, |c| c)?;
     let mut start = None;
     let mut end = None;
 
-    let width = input.lines().next().expect("Input cannot be empty").len();
-
-    for (y, line) in input.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            let mut node = Node {
-                coords: (x as i32, y as i32),
-                value: c,
-            };
-            if node.value == 'S' {
-                node = Node {
-                    coords: node.coords,
-                    value: 'a',
-                };
-                start = Some(node);
-            } else if node.value == 'E' {
-                node = Node {
-                    coords: node.coords,
-                    value: 'z',
-                };
-                end = Some(node);
+    let nodes: Vec<Node> = grid
+        .iter()
+        .map(|((x, y), &c)| {
+            let coords = (x as i32, y as i32);
+            match c {
+                'S' => *start.insert(Node { coords, value: 'a' }),
+                'E' => *end.insert(Node { coords, value: 'z' }),
+                value => Node { coords, value },
             }
-            nodes.push(node);
-        }
-    }
+        })
+        .collect();
 
-    for node in &nodes {
-        let mut adjacent_nodes = Vec::new();
-        for other_node in &nodes {
-            if node.distance(other_node) == 1
-                && (if reverse {
+    let mut edges = HashMap::new();
+    for (ix, node) in nodes.iter().enumerate() {
+        let (x, y) = grid.position(ix);
+        let adjacent_nodes = grid
+            .neighbours4(x, y)
+            .map(|(x, y)| nodes[grid.index(x, y)])
+            .filter(|other_node| {
+                if reverse {
                     node.value as i32 - other_node.value as i32 <= 1
                 } else {
                     other_node.value as i32 - node.value as i32 <= 1
-                })
-            {
-                adjacent_nodes.push(*other_node);
-            }
-        }
+                }
+            })
+            .collect();
         edges.insert(*node, adjacent_nodes);
     }
 
-    Graph {
+    Ok(Graph {
         vertices: nodes,
-        width,
+        width: grid.width(),
         edges,
-        start: start.expect("No start node found"),
-        end: end.expect("No end node found"),
-    }
+        start: start.ok_or_else(|| Error::parse(1, 1, "there is no start S"))?,
+        end: end.ok_or_else(|| Error::parse(1, 1, "there is no end E"))?,
+    })
 }
 
 /// A* algorithm for finding shortest path from Start node to End node.
-pub fn part_one(input: &str) -> Option<usize> {
-    let graph = parse_graph_from_grid_of_adjacent_chars(input, false);
+pub fn part_one(input: &str) -> Result<usize, Error> {
+    let graph = parse_graph_from_grid_of_adjacent_chars(input, false)?;
 
     let heuristic = |node: Node| node.distance(&graph.end) as usize;
 
@@ -115,7 +104,7 @@ pub fn part_one(input: &str) -> Option<usize> {
                 path.push(current);
                 current = *prev;
             }
-            return Some(path.len());
+            return Ok(path.len());
         }
 
         for neighbor in &graph.edges[&current] {
@@ -129,13 +118,13 @@ pub fn part_one(input: &str) -> Option<usize> {
         }
     }
 
-    None
+    Err(Error::Unsolved)
 }
 
 /// Dial's algorithm for finding paths from the End node to all other nodes.
 /// Complexity: O(E + V), but since E = 4V, this is O(V)
-pub fn part_two(input: &str) -> Option<usize> {
-    let graph = parse_graph_from_grid_of_adjacent_chars(input, true);
+pub fn part_two(input: &str) -> Result<usize, Error> {
+    let graph = parse_graph_from_grid_of_adjacent_chars(input, true)?;
 
     let mut dist = vec![usize::MAX; graph.vertices.len()];
     let mut queue = BucketQueue::<Vec<Node>>::new();
@@ -161,10 +150,11 @@ pub fn part_two(input: &str) -> Option<usize> {
         .filter(|&(ix, _)| graph.vertices[ix].value == 'a')
         .map(|(_, &d)| d)
         .min()
+        .ok_or(Error::Unsolved)
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 12);
+    let input = &advent_of_code::read_input(2022, 12);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -176,32 +166,29 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 12);
-        assert_eq!(part_one(&input), Some(31));
+        let input = advent_of_code::read_file("examples", 2022, 12).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 31);
+        assert!(matches!(
+            part_one("Sab\nabcE\n"),
+            Err(Error::Parse { line: 2, .. })
+        ));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 12);
-        assert_eq!(part_two(&input), Some(29));
-    }
-
-    #[test]
-    fn test_solution() {
-        let input = advent_of_code::read_file("inputs", 12);
-        assert_eq!(part_one(&input), Some(481));
-        assert_eq!(part_two(&input), Some(480));
+        let input = advent_of_code::read_file("examples", 2022, 12).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 29);
     }
 
     #[bench]
     fn bench_part_one(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 12);
+        let input = &advent_of_code::read_file("inputs", 2022, 12).unwrap();
         b.iter(|| part_one(input));
     }
 
     #[bench]
     fn bench_part_two(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 12);
+        let input = &advent_of_code::read_file("inputs", 2022, 12).unwrap();
         b.iter(|| part_two(input));
     }
 }
@@ -0,0 +1,186 @@
+#![feature(test)]
+#![feature(iter_array_chunks)]
+
+use advent_of_code::Error;
+use std::cmp::Ordering;
+
+#[derive(PartialEq, Eq, Clone)]
+enum Packet {
+    Single(u32),
+    List(Vec<Packet>),
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Single(a), Packet::Single(b)) => a.cmp(b),
+            (Packet::Single(a), ls) => Packet::List(vec![Packet::Single(*a)]).cmp(ls),
+            (ls, Packet::Single(a)) => ls.cmp(&Packet::List(vec![Packet::Single(*a)])),
+            (Packet::List(a), Packet::List(b)) => {
+                let mut ai = a.iter();
+                let mut bi = b.iter();
+
+                loop {
+                    match (ai.next(), bi.next()) {
+                        (Some(a), Some(b)) => {
+                            let cmp = a.cmp(b);
+                            if cmp != Ordering::Equal {
+                                return cmp;
+                            }
+                        }
+                        (Some(_), None) => return Ordering::Greater,
+                        (None, Some(_)) => return Ordering::Less,
+                        (None, None) => return Ordering::Equal,
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn parse_packet(line: &str) -> Result<Packet, Error> {
+    let mut stack = Vec::new();
+    let mut current = Vec::new();
+    let mut number = String::new();
+    // column where the number being read starts
+    let mut number_start = 0;
+
+    fn push_number(
+        current: &mut Vec<Packet>,
+        number: &mut String,
+        start: usize,
+    ) -> Result<(), Error> {
+        if !number.is_empty() {
+            let n = number
+                .parse()
+                .map_err(|_| Error::parse(1, start, format!("{} is too large", number)))?;
+            current.push(Packet::Single(n));
+            number.clear();
+        }
+        Ok(())
+    }
+
+    for (i, c) in line.chars().enumerate() {
+        match c {
+            '[' => {
+                stack.push(current);
+                current = Vec::new();
+            }
+            ']' => {
+                push_number(&mut current, &mut number, number_start)?;
+
+                if let Some(mut parent) = stack.pop() {
+                    parent.push(Packet::List(current));
+                    current = parent;
+                } else {
+                    return Err(Error::parse(1, i + 1, "unmatched ']'"));
+                }
+            }
+            ',' => push_number(&mut current, &mut number, number_start)?,
+            '0'..='9' => {
+                if number.is_empty() {
+                    number_start = i + 1;
+                }
+                number.push(c);
+            }
+            _ => return Err(Error::parse(1, i + 1, format!("unexpected {:?}", c))),
+        }
+    }
+
+    if !stack.is_empty() {
+        return Err(Error::parse(1, line.chars().count() + 1, "expected ']'"));
+    }
+    match (current.pop(), current.is_empty()) {
+        (Some(packet @ Packet::List(_)), true) => Ok(packet),
+        _ => Err(Error::parse(1, 1, "expected a single list")),
+    }
+}
+
+// the packets of all non-empty lines
+fn parse_packets(input: &str) -> Result<Vec<Packet>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| parse_packet(line).map_err(|e| e.on_line(i + 1)))
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Result<usize, Error> {
+    Ok(parse_packets(input)?
+        .into_iter()
+        .array_chunks::<2>()
+        .enumerate()
+        .map(|(ix, [left, right])| if left < right { ix + 1 } else { 0 })
+        .sum())
+}
+
+pub fn part_two(input: &str) -> Result<usize, Error> {
+    let mut trees = parse_packets(input)?;
+
+    let divisor = |x| Packet::List(vec![Packet::List(vec![Packet::Single(x)])]);
+    let d2 = divisor(2);
+    let d6 = divisor(6);
+
+    trees.push(d2.clone());
+    trees.push(d6.clone());
+
+    trees.sort_unstable();
+
+    Ok(trees
+        .iter()
+        .enumerate()
+        .filter(|(_, tree)| tree == &&d2 || tree == &&d6)
+        .map(|(ix, _)| ix + 1)
+        .product())
+}
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 13);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate test;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 13).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 13);
+        assert!(matches!(
+            part_one("[1,2]\n[3,[4]\n"),
+            Err(Error::Parse {
+                line: 2,
+                column: 7,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 13).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 140);
+    }
+
+    #[bench]
+    fn bench_part_one(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 13).unwrap();
+        b.iter(|| part_one(input));
+    }
+
+    #[bench]
+    fn bench_part_two(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 13).unwrap();
+        b.iter(|| part_two(input));
+    }
+}
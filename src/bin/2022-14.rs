@@ -1,23 +1,29 @@
 #![feature(test)]
 
+use advent_of_code::helpers::parse_lines;
+use advent_of_code::Error;
+
 type Point = (usize, usize);
 type Path = Vec<Point>;
 type Grid = Vec<Vec<char>>;
 
 const SOURCE_X: usize = 500;
 
-fn parse_input(input: &str) -> Vec<Vec<Point>> {
-    input
-        .lines()
-        .map(|line| {
-            line.split(" -> ")
-                .map(|p| {
-                    let (x, y) = p.split_once(',').unwrap();
-                    (x.parse().unwrap(), y.parse().unwrap())
-                })
-                .collect()
-        })
-        .collect()
+/// A line such as `498,4 -> 498,6 -> 496,6`.
+fn parse_path(line: &str) -> Result<Path, Error> {
+    let mut column = 1;
+    let mut path = Vec::new();
+
+    for point in line.split(" -> ") {
+        let parsed = point
+            .split_once(',')
+            .and_then(|(x, y)| Some((x.parse().ok()?, y.parse().ok()?)))
+            .ok_or_else(|| Error::parse(1, column, format!("{:?} is not a point", point)))?;
+        path.push(parsed);
+        column += point.chars().count() + " -> ".len();
+    }
+
+    Ok(path)
 }
 
 fn prepare_grid(paths: Vec<Path>, include_floor: bool) -> (Grid, usize) {
@@ -113,20 +119,20 @@ fn simulate_falling_sand(grid: &mut Grid, x_origin: usize) -> u32 {
     settled
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let paths = parse_input(input);
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    let paths = parse_lines(input, parse_path)?;
     let (mut grid, offset) = prepare_grid(paths, false);
-    Some(simulate_falling_sand(&mut grid, offset))
+    Ok(simulate_falling_sand(&mut grid, offset))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let paths = parse_input(input);
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    let paths = parse_lines(input, parse_path)?;
     let (mut grid, offset) = prepare_grid(paths, true);
-    Some(simulate_falling_sand(&mut grid, offset))
+    Ok(simulate_falling_sand(&mut grid, offset))
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 14);
+    let input = &advent_of_code::read_input(2022, 14);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -138,32 +144,33 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 14);
-        assert_eq!(part_one(&input), Some(24));
+        let input = advent_of_code::read_file("examples", 2022, 14).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 24);
+        assert!(matches!(
+            part_one("498,4 -> 498,6\n503,4 -> 502;4"),
+            Err(Error::Parse {
+                line: 2,
+                column: 10,
+                ..
+            })
+        ));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 14);
-        assert_eq!(part_two(&input), Some(93));
-    }
-
-    #[test]
-    fn test_solution() {
-        let input = advent_of_code::read_file("inputs", 14);
-        assert_eq!(part_one(&input), Some(793));
-        assert_eq!(part_two(&input), Some(24166));
+        let input = advent_of_code::read_file("examples", 2022, 14).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 93);
     }
 
     #[bench]
     fn bench_part_one(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 14);
+        let input = &advent_of_code::read_file("inputs", 2022, 14).unwrap();
         b.iter(|| part_one(input));
     }
 
     #[bench]
     fn bench_part_two(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 14);
+        let input = &advent_of_code::read_file("inputs", 2022, 14).unwrap();
         b.iter(|| part_two(input));
     }
 }
@@ -1,6 +1,7 @@
 #![feature(test)]
 
-use advent_of_code::helpers::parse_with_regex;
+use advent_of_code::helpers::{parse_lines, parse_with_regex};
+use advent_of_code::Error;
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::HashSet;
@@ -16,13 +17,13 @@ lazy_static! {
     .unwrap();
 }
 
-fn parse_line(line: &str) -> (Point, Point) {
-    let [sx, sy, bx, by] = parse_with_regex::<i32, 4>(&RE, line);
-    ((sx, sy), (bx, by))
+fn parse_line(line: &str) -> Result<(Point, Point), Error> {
+    let [sx, sy, bx, by] = parse_with_regex::<i32, 4>(&RE, line)?;
+    Ok(((sx, sy), (bx, by)))
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let data: Vec<(Point, Point)> = input.lines().map(parse_line).collect();
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    let data = parse_lines(input, parse_line)?;
 
     let manhattan =
         |a: Point, b: Point| (a.0.max(b.0) - a.0.min(b.0)) + (a.1.max(b.1) - a.1.min(b.1));
@@ -86,7 +87,7 @@ pub fn part_one(input: &str) -> Option<u32> {
         .map(|r| (*r.end() - *r.start()) as u32 + 1)
         .sum::<u32>();
 
-    Some(ranges_size - removed.len() as u32)
+    Ok(ranges_size - removed.len() as u32)
 }
 
 const COORD_MAX: i64 = 4_000_000;
@@ -162,8 +163,8 @@ impl<'ctx> PuzzleModel<'ctx> {
     }
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let data: Vec<(Point, Point)> = input.lines().map(parse_line).collect();
+pub fn part_two(input: &str) -> Result<u64, Error> {
+    let data = parse_lines(input, parse_line)?;
 
     let cfg = z3::Config::new();
     let ctx = z3::Context::new(&cfg);
@@ -176,15 +177,15 @@ pub fn part_two(input: &str) -> Option<u64> {
         z3::SatResult::Sat => {
             let model = solver.get_model().unwrap();
             let (bx, by) = puzzle_model.extract_model(&model);
-            Some(bx * 4_000_000 + by)
+            Ok(bx * 4_000_000 + by)
         }
-        _ => None,
+        _ => Err(Error::Unsolved),
     }
 }
 
 fn main() {
     let _ = RE.is_match(""); // pre-load regex
-    let input = &advent_of_code::read_file("inputs", 15);
+    let input = &advent_of_code::read_input(2022, 15);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -196,26 +197,23 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 15);
-        assert_eq!(part_one(&input), Some(26));
-    }
-
-    #[test]
-    fn test_solution() {
-        let input = advent_of_code::read_file("inputs", 15);
-        assert_eq!(part_one(&input), Some(5511201));
-        assert_eq!(part_two(&input), Some(11318723411840));
+        let input = advent_of_code::read_file("examples", 2022, 15).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 26);
+        assert!(matches!(
+            part_one("Sensor at x=2, y=18: closest beacon is at x=-2, y=1.5"),
+            Err(Error::Parse { line: 1, .. })
+        ));
     }
 
     #[bench]
     fn bench_part_one(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 15);
+        let input = &advent_of_code::read_file("inputs", 2022, 15).unwrap();
         b.iter(|| part_one(input));
     }
 
     #[bench]
     fn bench_part_two(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 15);
+        let input = &advent_of_code::read_file("inputs", 2022, 15).unwrap();
         b.iter(|| part_two(input));
     }
 }
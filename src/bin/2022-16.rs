@@ -0,0 +1,232 @@
+#![feature(test)]
+
+use advent_of_code::helpers::{parse_lines, parse_with_regex};
+use advent_of_code::Error;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashMap;
+
+lazy_static! {
+    static ref RE: Regex = Regex::new(r"^Valve \w+ has flow rate=(\d+);").unwrap();
+}
+
+struct Valve<'a> {
+    name: &'a str,
+    rate: u32,
+    tunnels: Vec<&'a str>,
+}
+
+fn parse_line(line: &str) -> Result<Valve<'_>, Error> {
+    let [rate] = parse_with_regex::<u32, 1>(&RE, line)?;
+    let (_, tunnels) = line.split_once(" valve").ok_or_else(|| {
+        Error::parse(
+            1,
+            line.chars().count() + 1,
+            "expected the tunnels to other valves",
+        )
+    })?;
+
+    Ok(Valve {
+        name: line[6..].split(' ').next().unwrap_or_default(),
+        rate,
+        tunnels: tunnels.trim_start_matches('s').trim().split(", ").collect(),
+    })
+}
+
+/// Every subset of the useful valves gets a slot in the table built by
+/// [`Network::best_per_subset`], so keep it at 64 MiB.
+const MAX_USEFUL_VALVES: usize = 24;
+
+/// The valve network compressed to the starting valve (at index 0)
+/// and the valves with non-zero flow.
+struct Network {
+    rates: Vec<u32>,
+    /// Travel time between each pair of valves, plus one minute to open the destination.
+    costs: Vec<Vec<u32>>,
+}
+
+fn parse_network(input: &str) -> Result<Network, Error> {
+    let valves = parse_lines(input, parse_line)?;
+    let index: HashMap<&str, usize> = valves
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.name, i))
+        .collect();
+
+    // all-pairs shortest paths (Floyd-Warshall)
+    let n = valves.len();
+    let mut dist = vec![vec![u32::MAX / 2; n]; n];
+
+    for (i, valve) in valves.iter().enumerate() {
+        dist[i][i] = 0;
+        for tunnel in valve.tunnels.iter() {
+            let j = index
+                .get(tunnel)
+                .ok_or_else(|| Error::parse(i + 1, 1, format!("there is no valve {}", tunnel)))?;
+            dist[i][*j] = 1;
+        }
+    }
+
+    for k in 0..n {
+        for i in 0..n {
+            for j in 0..n {
+                dist[i][j] = dist[i][j].min(dist[i][k] + dist[k][j]);
+            }
+        }
+    }
+
+    // keep only the valves worth visiting, with the starting valve first
+    let start = *index
+        .get("AA")
+        .ok_or_else(|| Error::parse(1, 1, "there is no valve AA to start from"))?;
+    let useful = std::iter::once(start)
+        .chain((0..n).filter(|&i| i != start && valves[i].rate > 0))
+        .collect::<Vec<_>>();
+
+    if let Some(&extra) = useful.get(MAX_USEFUL_VALVES + 1) {
+        return Err(Error::parse(
+            extra + 1,
+            1,
+            format!(
+                "more than {} valves with a non-zero flow rate",
+                MAX_USEFUL_VALVES
+            ),
+        ));
+    }
+
+    Ok(Network {
+        rates: useful.iter().map(|&i| valves[i].rate).collect(),
+        costs: useful
+            .iter()
+            .map(|&i| useful.iter().map(|&j| dist[i][j] + 1).collect())
+            .collect(),
+    })
+}
+
+impl Network {
+    /// Record in `best` the highest pressure released for every set of opened valves
+    /// reachable from `current` within `time` minutes.
+    fn explore(&self, current: usize, time: u32, opened: u64, released: u32, best: &mut [u32]) {
+        let entry = &mut best[opened as usize];
+        *entry = (*entry).max(released);
+
+        for next in 1..self.rates.len() {
+            let cost = self.costs[current][next];
+            if opened & (1 << (next - 1)) != 0 || cost >= time {
+                continue;
+            }
+
+            let time = time - cost;
+            self.explore(
+                next,
+                time,
+                opened | (1 << (next - 1)),
+                released + time * self.rates[next],
+                best,
+            );
+        }
+    }
+
+    fn best_per_subset(&self, time: u32) -> Vec<u32> {
+        let mut best = vec![0; 1 << (self.rates.len() - 1)];
+        self.explore(0, time, 0, 0, &mut best);
+        best
+    }
+}
+
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    let network = parse_network(input)?;
+    network
+        .best_per_subset(30)
+        .into_iter()
+        .max()
+        .ok_or(Error::Unsolved)
+}
+
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    let network = parse_network(input)?;
+    let mut best = network.best_per_subset(26);
+
+    // propagate the best result of every subset to all of its supersets
+    let valves = network.rates.len() - 1;
+    for bit in 0..valves {
+        for mask in 0..best.len() {
+            if mask & (1 << bit) != 0 {
+                best[mask] = best[mask].max(best[mask ^ (1 << bit)]);
+            }
+        }
+    }
+
+    // the elephant opens a subset of the valves we leave closed
+    let all = best.len() - 1;
+    (0..best.len())
+        .map(|mine| best[mine] + best[all ^ mine])
+        .max()
+        .ok_or(Error::Unsolved)
+}
+
+fn main() {
+    let _ = RE.is_match(""); // pre-load regex
+    let input = &advent_of_code::read_input(2022, 16);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate test;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 16).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 1651);
+
+        let input = input.replace("valves DD, II, BB", "valves DD, XX, BB");
+        assert!(matches!(
+            part_one(&input),
+            Err(Error::Parse { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 16).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 1707);
+
+        let valves = (0..=MAX_USEFUL_VALVES)
+            .map(|i| format!("V{}", i))
+            .collect::<Vec<_>>();
+        let input = std::iter::once(format!(
+            "Valve AA has flow rate=0; tunnels lead to valves {}",
+            valves.join(", ")
+        ))
+        .chain(
+            valves
+                .iter()
+                .map(|v| format!("Valve {} has flow rate=1; tunnel leads to valve AA", v)),
+        )
+        .collect::<Vec<_>>()
+        .join("\n");
+        assert!(matches!(
+            part_two(&input),
+            Err(Error::Parse {
+                line: 26,
+                column: 1,
+                ..
+            })
+        ));
+    }
+
+    #[bench]
+    fn bench_part_one(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 16).unwrap();
+        b.iter(|| part_one(input));
+    }
+
+    #[bench]
+    fn bench_part_two(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 16).unwrap();
+        b.iter(|| part_two(input));
+    }
+}
@@ -0,0 +1,223 @@
+#![feature(test)]
+
+use advent_of_code::Error;
+use std::collections::HashMap;
+
+/// Rock shapes, bottom row first, already shifted two units away from the left wall.
+/// Bit 6 is the leftmost column of the chamber, bit 0 the rightmost.
+const ROCKS: [&[u8]; 5] = [
+    &[0b0011110],
+    &[0b0001000, 0b0011100, 0b0001000],
+    &[0b0011100, 0b0000100, 0b0000100],
+    &[0b0010000, 0b0010000, 0b0010000, 0b0010000],
+    &[0b0011000, 0b0011000],
+];
+
+const WIDTH: usize = 7;
+
+struct Chamber<'a> {
+    jets: &'a [u8],
+    jet: usize,
+    rock: usize,
+    rows: Vec<u8>,
+}
+
+impl<'a> Chamber<'a> {
+    fn new(jets: &'a [u8]) -> Self {
+        Chamber {
+            jets,
+            jet: 0,
+            rock: 0,
+            rows: Vec::with_capacity(4096),
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn collides(&self, shape: &[u8], bottom: usize) -> bool {
+        shape
+            .iter()
+            .enumerate()
+            .any(|(dy, &row)| self.rows.get(bottom + dy).is_some_and(|&r| r & row != 0))
+    }
+
+    fn drop_rock(&mut self) {
+        let mut shape = ROCKS[self.rock].to_vec();
+        let mut bottom = self.height() + 3;
+
+        loop {
+            // get pushed by a jet of hot gas, if nothing is in the way
+            let pushed = match self.jets[self.jet] {
+                b'<' if shape.iter().all(|&r| r & 0b1000000 == 0) => {
+                    Some(shape.iter().map(|&r| r << 1).collect::<Vec<_>>())
+                }
+                b'>' if shape.iter().all(|&r| r & 0b0000001 == 0) => {
+                    Some(shape.iter().map(|&r| r >> 1).collect::<Vec<_>>())
+                }
+                _ => None,
+            };
+            self.jet = (self.jet + 1) % self.jets.len();
+
+            if let Some(pushed) = pushed {
+                if !self.collides(&pushed, bottom) {
+                    shape = pushed;
+                }
+            }
+
+            // fall one unit down, or come to rest
+            if bottom == 0 || self.collides(&shape, bottom - 1) {
+                break;
+            }
+            bottom -= 1;
+        }
+
+        for (dy, &row) in shape.iter().enumerate() {
+            if bottom + dy == self.rows.len() {
+                self.rows.push(0);
+            }
+            self.rows[bottom + dy] |= row;
+        }
+
+        self.rock = (self.rock + 1) % ROCKS.len();
+    }
+
+    /// Depth of the topmost rock in each column, relative to the top of the tower.
+    fn surface(&self) -> [usize; WIDTH] {
+        let mut depths = [self.height(); WIDTH];
+        for (x, depth) in depths.iter_mut().enumerate() {
+            let bit = 1 << (WIDTH - 1 - x);
+            if let Some(d) = self.rows.iter().rev().position(|&r| r & bit != 0) {
+                *depth = d;
+            }
+        }
+        depths
+    }
+}
+
+/// The jet pattern, at least one `<` or `>`.
+fn parse_jets(input: &str) -> Result<&[u8], Error> {
+    let start = input.len() - input.trim_start().len();
+    let jets = input.trim();
+    if jets.is_empty() {
+        return Err(Error::parse_at(input, start, "expected a jet pattern"));
+    }
+    if let Some((i, c)) = jets.char_indices().find(|&(_, c)| c != '<' && c != '>') {
+        return Err(Error::parse_at(
+            input,
+            start + i,
+            format!("unexpected {:?}", c),
+        ));
+    }
+    Ok(jets.as_bytes())
+}
+
+fn tower_height(jets: &[u8], rocks: usize) -> usize {
+    let mut chamber = Chamber::new(jets);
+
+    // rocks dropped and tower height, keyed by the state of the chamber before the drop
+    let mut seen = HashMap::new();
+    let mut skipped_height = 0;
+
+    let mut dropped = 0;
+    while dropped < rocks {
+        let key = (chamber.rock, chamber.jet, chamber.surface());
+
+        if skipped_height == 0 {
+            if let Some((prev_dropped, prev_height)) = seen.insert(key, (dropped, chamber.height()))
+            {
+                // the same state came up before - repeat the cycle as many times as possible
+                let period = dropped - prev_dropped;
+                let cycles = (rocks - dropped) / period;
+                skipped_height = cycles * (chamber.height() - prev_height);
+                dropped += cycles * period;
+
+                if dropped == rocks {
+                    break;
+                }
+            }
+        }
+
+        chamber.drop_rock();
+        dropped += 1;
+    }
+
+    chamber.height() + skipped_height
+}
+
+pub fn part_one(input: &str) -> Result<usize, Error> {
+    Ok(tower_height(parse_jets(input)?, 2022))
+}
+
+pub fn part_two(input: &str) -> Result<usize, Error> {
+    Ok(tower_height(parse_jets(input)?, 1_000_000_000_000))
+}
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 17);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate test;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 17).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 3068);
+
+        assert!(matches!(
+            part_one(" \n"),
+            Err(Error::Parse {
+                line: 2,
+                column: 1,
+                ..
+            })
+        ));
+        assert!(matches!(
+            part_one("\n<<>x>"),
+            Err(Error::Parse {
+                line: 2,
+                column: 4,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 17).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 1514285714288);
+    }
+
+    #[test]
+    fn test_cycle_detection() {
+        let input = advent_of_code::read_file("examples", 2022, 17).unwrap();
+        let jets = parse_jets(&input).unwrap();
+
+        // compare against a plain simulation
+        let mut chamber = Chamber::new(jets);
+        for rocks in 1..=5000 {
+            chamber.drop_rock();
+            if rocks % 997 == 0 {
+                assert_eq!(tower_height(jets, rocks), chamber.height());
+            }
+        }
+    }
+
+    #[bench]
+    fn bench_part_one(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 17).unwrap();
+        b.iter(|| part_one(input));
+    }
+
+    #[bench]
+    fn bench_part_two(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 17).unwrap();
+        b.iter(|| part_two(input));
+    }
+}
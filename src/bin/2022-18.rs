@@ -1,23 +1,24 @@
 #![feature(test)]
 
-use advent_of_code::helpers::parse_with_regex;
+use advent_of_code::helpers::{parse_lines, parse_with_regex};
+use advent_of_code::Error;
 use lazy_static::lazy_static;
 use regex::Regex;
 
 type Point = (usize, usize, usize);
 
 lazy_static! {
-    static ref RE: Regex = Regex::new(r"(\d+),(\d+),(\d+)").unwrap();
+    static ref RE: Regex = Regex::new(r"^(\d+),(\d+),(\d+)$").unwrap();
 }
 
 // parse CSV lines into points
-fn parse_line(line: &str) -> Point {
-    let [x, y, z] = parse_with_regex::<usize, 3>(&RE, line);
-    (x, y, z)
+fn parse_line(line: &str) -> Result<Point, Error> {
+    let [x, y, z] = parse_with_regex::<usize, 3>(&RE, line)?;
+    Ok((x, y, z))
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let points = input.lines().map(parse_line).collect::<Vec<_>>();
+pub fn part_one(input: &str) -> Result<usize, Error> {
+    let points = parse_lines(input, parse_line)?;
 
     // find max dimensions
     let max_x = points.iter().map(|(x, _, _)| x).max().unwrap() + 2;
@@ -42,21 +43,18 @@ pub fn part_one(input: &str) -> Option<usize> {
         sides[x + 1][y][z][2] += 1;
     }
 
-    Some(
-        sides
-            .iter()
-            .flatten()
-            .flatten()
-            .flatten()
-            .filter(|&&x| x == 1)
-            .count(),
-    )
+    Ok(sides
+        .iter()
+        .flatten()
+        .flatten()
+        .flatten()
+        .filter(|&&x| x == 1)
+        .count())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let points = input
-        .lines()
-        .map(parse_line)
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    let points = parse_lines(input, parse_line)?
+        .into_iter()
         .map(|(x, y, z)| (x + 1, y + 1, z + 1))
         .collect::<Vec<_>>();
 
@@ -135,19 +133,17 @@ pub fn part_two(input: &str) -> Option<u32> {
         }
     }
 
-    Some(
-        reached_sides
-            .iter()
-            .flatten()
-            .flatten()
-            .flatten()
-            .map(|&side| side as u32)
-            .sum(),
-    )
+    Ok(reached_sides
+        .iter()
+        .flatten()
+        .flatten()
+        .flatten()
+        .map(|&side| side as u32)
+        .sum())
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 18);
+    let input = &advent_of_code::read_input(2022, 18);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -159,32 +155,29 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 18);
-        assert_eq!(part_one(&input), Some(64));
+        let input = advent_of_code::read_file("examples", 2022, 18).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 64);
+        assert!(matches!(
+            part_one("2,2,2\n1,2,-2"),
+            Err(Error::Parse { line: 2, .. })
+        ));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 18);
-        assert_eq!(part_two(&input), Some(58));
-    }
-
-    #[test]
-    fn test_solution() {
-        let input = advent_of_code::read_file("inputs", 18);
-        assert_eq!(part_one(&input), Some(3326));
-        assert_eq!(part_two(&input), Some(1996));
+        let input = advent_of_code::read_file("examples", 2022, 18).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 58);
     }
 
     #[bench]
     fn bench_part_one(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 18);
+        let input = &advent_of_code::read_file("inputs", 2022, 18).unwrap();
         b.iter(|| part_one(input));
     }
 
     #[bench]
     fn bench_part_two(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 18);
+        let input = &advent_of_code::read_file("inputs", 2022, 18).unwrap();
         b.iter(|| part_two(input));
     }
 }
@@ -0,0 +1,258 @@
+#![feature(test)]
+
+use advent_of_code::helpers::{parse_lines, parse_with_regex};
+use advent_of_code::Error;
+use lazy_static::lazy_static;
+use regex::Regex;
+use z3::ast::Ast;
+
+lazy_static! {
+    static ref RE: Regex = Regex::new(
+        r"^Blueprint (\d+): Each ore robot costs (\d+) ore. Each clay robot costs (\d+) ore. Each obsidian robot costs (\d+) ore and (\d+) clay. Each geode robot costs (\d+) ore and (\d+) obsidian.$"
+    )
+    .unwrap();
+}
+
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+type Resources = [u32; 4];
+
+struct Blueprint {
+    id: u32,
+    /// Cost of each kind of robot, indexed by the resource it collects.
+    costs: [Resources; 4],
+    /// No point in building more robots of a kind than can be spent in a single minute.
+    max_robots: Resources,
+}
+
+fn parse_line(line: &str) -> Result<Blueprint, Error> {
+    let [id, ore_ore, clay_ore, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian] =
+        parse_with_regex::<u32, 7>(&RE, line)?;
+
+    let costs = [
+        [ore_ore, 0, 0, 0],
+        [clay_ore, 0, 0, 0],
+        [obsidian_ore, obsidian_clay, 0, 0],
+        [geode_ore, 0, geode_obsidian, 0],
+    ];
+
+    let max_robots = [
+        ore_ore.max(clay_ore).max(obsidian_ore).max(geode_ore),
+        obsidian_clay,
+        geode_obsidian,
+        u32::MAX,
+    ];
+
+    Ok(Blueprint {
+        id,
+        costs,
+        max_robots,
+    })
+}
+
+impl Blueprint {
+    /// Branch-and-bound search over which robot to build next.
+    fn search(&self, time: u32, robots: Resources, stock: Resources, geodes: u32, best: &mut u32) {
+        *best = (*best).max(geodes);
+
+        // optimistic bound: build a geode robot every remaining minute
+        if geodes + time * time.saturating_sub(1) / 2 <= *best {
+            return;
+        }
+
+        for kind in (ORE..=GEODE).rev() {
+            if robots[kind] >= self.max_robots[kind] {
+                continue;
+            }
+
+            // minutes to wait until the robot is affordable
+            let mut wait = 0;
+            let mut affordable = true;
+            for res in ORE..GEODE {
+                let cost = self.costs[kind][res];
+                if cost > stock[res] {
+                    if robots[res] == 0 {
+                        affordable = false;
+                        break;
+                    }
+                    wait = wait.max((cost - stock[res]).div_ceil(robots[res]));
+                }
+            }
+
+            // the new robot must have at least one minute left to collect anything
+            if !affordable || wait + 1 >= time {
+                continue;
+            }
+
+            let time = time - wait - 1;
+            let mut stock = stock;
+            for res in ORE..GEODE {
+                stock[res] = stock[res] + robots[res] * (wait + 1) - self.costs[kind][res];
+            }
+
+            if kind == GEODE {
+                self.search(time, robots, stock, geodes + time, best);
+            } else {
+                let mut robots = robots;
+                robots[kind] += 1;
+                self.search(time, robots, stock, geodes, best);
+            }
+        }
+    }
+
+    fn max_geodes(&self, minutes: u32) -> u32 {
+        let mut best = 0;
+        self.search(minutes, [1, 0, 0, 0], [0; 4], 0, &mut best);
+        best
+    }
+
+    /// Solve the same problem as an integer program: one 0/1 variable per minute
+    /// and kind of robot, maximizing the geodes opened at the end.
+    fn max_geodes_ilp(&self, minutes: u32) -> u32 {
+        use z3::ast::Int;
+
+        let cfg = z3::Config::new();
+        let ctx = z3::Context::new(&cfg);
+        let optimize = z3::Optimize::new(&ctx);
+
+        let zero = Int::from_i64(&ctx, 0);
+        let one = Int::from_i64(&ctx, 1);
+
+        let mut robots = [1, 0, 0, 0].map(|r| Int::from_i64(&ctx, r));
+        let mut stock = [0; 4].map(|_| zero.clone());
+
+        for minute in 0..minutes {
+            let build = [ORE, CLAY, OBSIDIAN, GEODE]
+                .map(|kind| Int::new_const(&ctx, format!("build_{}_{}", kind, minute)));
+
+            for b in build.iter() {
+                optimize.assert(&b.ge(&zero));
+                optimize.assert(&b.le(&one));
+            }
+            optimize.assert(&Int::add(&ctx, &build.iter().collect::<Vec<_>>()).le(&one));
+
+            // pay for the robot with the resources available at the start of the minute
+            for (res, stock) in stock.iter_mut().enumerate().take(GEODE) {
+                let spent = build
+                    .iter()
+                    .enumerate()
+                    .map(|(kind, b)| b * Int::from_u64(&ctx, self.costs[kind][res] as u64))
+                    .collect::<Vec<_>>();
+                let spent = Int::add(&ctx, &spent.iter().collect::<Vec<_>>());
+                optimize.assert(&spent.le(stock));
+                *stock = &*stock - &spent;
+            }
+
+            // existing robots collect, then the new robot is ready
+            for kind in ORE..=GEODE {
+                stock[kind] = &stock[kind] + &robots[kind];
+                robots[kind] = &robots[kind] + &build[kind];
+            }
+        }
+
+        optimize.maximize(&stock[GEODE]);
+
+        match optimize.check(&[]) {
+            z3::SatResult::Sat => {
+                let model = optimize.get_model().unwrap();
+                model
+                    .eval(&stock[GEODE].simplify(), true)
+                    .unwrap()
+                    .as_u64()
+                    .unwrap() as u32
+            }
+            _ => 0,
+        }
+    }
+}
+
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    Ok(parse_lines(input, parse_line)?
+        .iter()
+        .map(|bp| bp.id * bp.max_geodes(24))
+        .sum())
+}
+
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    Ok(parse_lines(input, parse_line)?
+        .iter()
+        .take(3)
+        .map(|bp| bp.max_geodes(32))
+        .product())
+}
+
+/// Check the search against the integer program for every blueprint.
+fn cross_check(input: &str, minutes: u32) -> Result<(), Error> {
+    for bp in parse_lines(input, parse_line)? {
+        let (dfs, ilp) = (bp.max_geodes(minutes), bp.max_geodes_ilp(minutes));
+        println!(
+            "Blueprint {}: {} geodes (search), {} geodes (integer program){}",
+            bp.id,
+            dfs,
+            ilp,
+            if dfs == ilp { "" } else { " MISMATCH" }
+        );
+    }
+    Ok(())
+}
+
+fn main() {
+    let _ = RE.is_match(""); // pre-load regex
+    let input = &advent_of_code::read_input(2022, 19);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+
+    // `cargo solve 19 -- --cross-check` verifies part one with Z3
+    if std::env::args().any(|arg| arg == "--cross-check") {
+        if let Ok(input) = input {
+            if let Err(e) = cross_check(input, 24) {
+                eprintln!("Failed to cross-check: {}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate test;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 19).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 33);
+        assert!(matches!(
+            part_one("Blueprint 1: Each ore robot costs 4 ore."),
+            Err(Error::Parse { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 19).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 56 * 62);
+    }
+
+    #[test]
+    fn test_integer_program() {
+        let input = advent_of_code::read_file("examples", 2022, 19).unwrap();
+        for bp in parse_lines(&input, parse_line).unwrap() {
+            assert_eq!(bp.max_geodes_ilp(24), bp.max_geodes(24));
+        }
+    }
+
+    #[bench]
+    fn bench_part_one(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 19).unwrap();
+        b.iter(|| part_one(input));
+    }
+
+    #[bench]
+    fn bench_part_two(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 19).unwrap();
+        b.iter(|| part_two(input));
+    }
+}
@@ -1,10 +1,16 @@
 #![feature(test)]
 
-pub fn part_one(input: &str) -> Option<i32> {
-    let nums = input
-        .lines()
-        .map(|line| line.parse::<i32>().unwrap())
-        .collect::<Vec<_>>();
+use advent_of_code::helpers::parse_lines;
+use advent_of_code::Error;
+use std::str::FromStr;
+
+fn parse_number<T: FromStr>(line: &str) -> Result<T, Error> {
+    line.parse()
+        .map_err(|_| Error::parse(1, 1, format!("{:?} is not a number", line)))
+}
+
+pub fn part_one(input: &str) -> Result<i32, Error> {
+    let nums = parse_lines(input, parse_number::<i32>)?;
 
     let len = nums.len() as i32;
 
@@ -40,18 +46,13 @@ pub fn part_one(input: &str) -> Option<i32> {
     let ix_fst = (zero_ix + 1000) % len;
     let ix_snd = (zero_ix + 2000) % len;
     let ix_trd = (zero_ix + 3000) % len;
-    Some(
-        nums[mixed2orig[ix_fst] as usize]
-            + nums[mixed2orig[ix_snd] as usize]
-            + nums[mixed2orig[ix_trd] as usize],
-    )
+    Ok(nums[mixed2orig[ix_fst] as usize]
+        + nums[mixed2orig[ix_snd] as usize]
+        + nums[mixed2orig[ix_trd] as usize])
 }
 
-pub fn part_two(input: &str) -> Option<i64> {
-    let nums = input
-        .lines()
-        .map(|line| line.parse::<i64>().unwrap() * 811589153)
-        .collect::<Vec<_>>();
+pub fn part_two(input: &str) -> Result<i64, Error> {
+    let nums = parse_lines(input, |line| Ok(parse_number::<i64>(line)? * 811589153))?;
 
     let len = nums.len() as i64;
 
@@ -89,15 +90,13 @@ pub fn part_two(input: &str) -> Option<i64> {
     let ix_fst = (zero_ix + 1000) % len;
     let ix_snd = (zero_ix + 2000) % len;
     let ix_trd = (zero_ix + 3000) % len;
-    Some(
-        nums[mixed2orig[ix_fst] as usize]
-            + nums[mixed2orig[ix_snd] as usize]
-            + nums[mixed2orig[ix_trd] as usize],
-    )
+    Ok(nums[mixed2orig[ix_fst] as usize]
+        + nums[mixed2orig[ix_snd] as usize]
+        + nums[mixed2orig[ix_trd] as usize])
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 20);
+    let input = &advent_of_code::read_input(2022, 20);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -109,32 +108,29 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 20);
-        assert_eq!(part_one(&input), Some(3));
+        let input = advent_of_code::read_file("examples", 2022, 20).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 3);
+        assert!(matches!(
+            part_one("1\n2\n-x\n0"),
+            Err(Error::Parse { line: 3, .. })
+        ));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 20);
-        assert_eq!(part_two(&input), Some(1623178306));
-    }
-
-    #[test]
-    fn test_solution() {
-        let input = advent_of_code::read_file("inputs", 20);
-        assert_eq!(part_one(&input), Some(2203));
-        assert_eq!(part_two(&input), Some(6641234038999));
+        let input = advent_of_code::read_file("examples", 2022, 20).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 1623178306);
     }
 
     #[bench]
     fn bench_part_one(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 20);
+        let input = &advent_of_code::read_file("inputs", 2022, 20).unwrap();
         b.iter(|| part_one(input));
     }
 
     #[bench]
     fn bench_part_two(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 20);
+        let input = &advent_of_code::read_file("inputs", 2022, 20).unwrap();
         b.iter(|| part_two(input));
     }
 }
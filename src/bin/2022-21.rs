@@ -1,5 +1,7 @@
 #![feature(test)]
 
+use advent_of_code::helpers::parse_lines;
+use advent_of_code::Error;
 use std::collections::HashMap;
 use z3::ast::Ast;
 
@@ -9,17 +11,27 @@ enum Value {
     App(String, char, String),
 }
 
-fn parse_line(line: &str) -> (String, Value) {
-    let monkey = line[..4].to_string();
-    if line.len() == 17 {
-        let left = line[6..=9].to_string();
-        let op = line.chars().nth(11).unwrap();
-        let right = line[13..=16].to_string();
-        (monkey, Value::App(left, op, right))
-    } else {
-        let val = line[6..].parse().unwrap();
-        (monkey, Value::Const(val))
-    }
+/// A line such as `root: pppw + sjmn` or `dbpl: 5`.
+fn parse_line(line: &str) -> Result<(String, Value), Error> {
+    let (monkey, job) = line
+        .split_once(": ")
+        .ok_or_else(|| Error::parse(1, 1, "expected a monkey and its job"))?;
+    let column = monkey.chars().count() + 3;
+
+    let value = match job.split(' ').collect::<Vec<_>>()[..] {
+        [left, op @ ("+" | "-" | "*" | "/"), right] => Value::App(
+            left.to_string(),
+            op.chars().next().unwrap(),
+            right.to_string(),
+        ),
+        [number] => Value::Const(
+            number
+                .parse()
+                .map_err(|_| Error::parse(1, column, format!("{:?} is not a number", number)))?,
+        ),
+        _ => return Err(Error::parse(1, column, "expected a number or an operation")),
+    };
+    Ok((monkey.to_string(), value))
 }
 
 fn solve(monkey: String, monkeys: &mut HashMap<String, Value>) -> i64 {
@@ -44,13 +56,13 @@ fn solve(monkey: String, monkeys: &mut HashMap<String, Value>) -> i64 {
     }
 }
 
-pub fn part_one(input: &str) -> Option<i64> {
-    let mut monkeys = input.lines().map(parse_line).collect();
-    Some(solve("root".to_string(), &mut monkeys))
+pub fn part_one(input: &str) -> Result<i64, Error> {
+    let mut monkeys = parse_lines(input, parse_line)?.into_iter().collect();
+    Ok(solve("root".to_string(), &mut monkeys))
 }
 
-pub fn part_two(input: &str) -> Option<i64> {
-    let monkeys = input.lines().map(parse_line).collect::<Vec<_>>();
+pub fn part_two(input: &str) -> Result<i64, Error> {
+    let monkeys = parse_lines(input, parse_line)?;
 
     let cfg = z3::Config::new();
     let ctx = z3::Context::new(&cfg);
@@ -107,14 +119,14 @@ pub fn part_two(input: &str) -> Option<i64> {
                 .unwrap()
                 .as_real()
                 .unwrap();
-            Some(n / d)
+            Ok(n / d)
         }
-        _ => None,
+        _ => Err(Error::Unsolved),
     }
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 21);
+    let input = &advent_of_code::read_input(2022, 21);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -126,32 +138,33 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 21);
-        assert_eq!(part_one(&input), Some(152));
+        let input = advent_of_code::read_file("examples", 2022, 21).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 152);
+        assert!(matches!(
+            part_one("root: pppw + sjmn\ndbpl: five"),
+            Err(Error::Parse {
+                line: 2,
+                column: 7,
+                ..
+            })
+        ));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 21);
-        assert_eq!(part_two(&input), Some(301));
-    }
-
-    #[test]
-    fn test_solution() {
-        let input = advent_of_code::read_file("inputs", 21);
-        assert_eq!(part_one(&input), Some(72664227897438));
-        assert_eq!(part_two(&input), Some(3916491093817));
+        let input = advent_of_code::read_file("examples", 2022, 21).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 301);
     }
 
     #[bench]
     fn bench_part_one(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 21);
+        let input = &advent_of_code::read_file("inputs", 2022, 21).unwrap();
         b.iter(|| part_one(input));
     }
 
     #[bench]
     fn bench_part_two(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 21);
+        let input = &advent_of_code::read_file("inputs", 2022, 21).unwrap();
         b.iter(|| part_two(input));
     }
 }
@@ -0,0 +1,543 @@
+#![feature(test)]
+
+use advent_of_code::Error;
+
+#[derive(Debug)]
+enum Command {
+    Forward(u32),
+    Turn(char),
+}
+
+fn parse(input: &str) -> Result<(Vec<Vec<char>>, Vec<Command>), Error> {
+    let lines = input.lines().collect::<Vec<_>>();
+
+    // the map and the path are separated by an empty line
+    let (lines, path) = match lines.as_slice() {
+        [map @ .., "", path] if !map.is_empty() => (map, *path),
+        _ => {
+            return Err(Error::parse(
+                lines.len().max(1),
+                1,
+                "expected a map, an empty line and a path",
+            ))
+        }
+    };
+
+    let width = lines.iter().map(|x| x.len()).max().unwrap();
+    let height = lines.len();
+
+    let mut map = vec![vec![' '; width + 2]; height + 2];
+
+    for (y, line) in lines.iter().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            if !matches!(c, ' ' | '.' | '#') {
+                return Err(Error::parse(y + 1, x + 1, format!("unexpected {:?}", c)));
+            }
+            map[y + 1][x + 1] = c;
+        }
+    }
+
+    if !lines[0].contains('.') {
+        return Err(Error::parse(
+            1,
+            1,
+            "the first row has no open tile to start on",
+        ));
+    }
+
+    let path_line = height + 2;
+    // the steps in `num`, which ends right before column `end`
+    let steps = |num: &str, end: usize| {
+        num.parse()
+            .map_err(|_| Error::parse(path_line, end - num.len(), "expected a number of steps"))
+    };
+
+    let mut commands = Vec::with_capacity(path.len() / 2);
+    let mut num = String::with_capacity(8);
+    for (i, c) in path.chars().enumerate() {
+        match c {
+            '0'..='9' => {
+                num.push(c);
+            }
+            'L' | 'R' => {
+                commands.push(Command::Forward(steps(&num, i + 1)?));
+                num.clear();
+                commands.push(Command::Turn(c))
+            }
+            _ => {
+                return Err(Error::parse(
+                    path_line,
+                    i + 1,
+                    format!("unexpected {:?}", c),
+                ))
+            }
+        }
+    }
+
+    if !num.is_empty() {
+        commands.push(Command::Forward(steps(&num, path.chars().count() + 1)?));
+    }
+
+    Ok((map, commands))
+}
+
+pub fn part_one(input: &str) -> Result<i32, Error> {
+    let (map, commands) = parse(input)?;
+
+    // find starting point
+    let mut r = 1;
+    let mut c = 1;
+
+    'search: for (y, row) in map.iter().enumerate() {
+        for (x, tile) in row.iter().enumerate() {
+            if *tile == '.' {
+                (r, c) = (y as i32, x as i32);
+                break 'search;
+            }
+        }
+    }
+
+    let invalid = |y: i32, x: i32| map[y as usize][x as usize] == ' ';
+
+    // traverse the map
+    let mut direction = (1, 0);
+
+    for command in commands.iter() {
+        match command {
+            Command::Turn('R') => {
+                direction = (-direction.1, direction.0);
+            }
+            Command::Turn('L') => {
+                direction = (direction.1, -direction.0);
+            }
+            &Command::Forward(steps) => {
+                for _ in 0..steps {
+                    let (old_r, old_c) = (r, c);
+
+                    // make one step in direction
+                    (r, c) = (r + direction.1, c + direction.0);
+
+                    // check if we are out of bounds
+                    if invalid(r, c) {
+                        // go as far as possible in the oposite direction until ' ' encountered
+                        while !invalid(r - direction.1, c - direction.0) {
+                            c -= direction.0;
+                            r -= direction.1;
+                        }
+                    }
+                    // check if we are standing on top of an obstacle ('#')
+                    if map[r as usize][c as usize] == '#' {
+                        (r, c) = (old_r, old_c);
+                        break;
+                    }
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    let facing = match direction {
+        (1, 0) => 0,
+        (0, -1) => 1,
+        (-1, 0) => 2,
+        (0, 1) => 3,
+        _ => unreachable!(),
+    };
+
+    Ok(r * 1000 + c * 4 + facing)
+}
+
+type Vec3 = [i32; 3];
+
+fn dot(a: Vec3, b: Vec3) -> i32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn neg(a: Vec3) -> Vec3 {
+    [-a[0], -a[1], -a[2]]
+}
+
+/// One face of the folded cube: its tile in the net and the 3D orientation of its
+/// outward normal and of the net's "right" and "down" directions on it.
+struct Face {
+    tile: (usize, usize),
+    normal: Vec3,
+    right: Vec3,
+    down: Vec3,
+}
+
+impl Face {
+    /// 3D vector corresponding to a 2D direction (dx, dy) on this face.
+    fn to_3d(&self, (dx, dy): (i32, i32)) -> Vec3 {
+        let mut v = [0; 3];
+        for (i, x) in v.iter_mut().enumerate() {
+            *x = dx * self.right[i] + dy * self.down[i];
+        }
+        v
+    }
+
+    /// 2D direction (dx, dy) on this face corresponding to a 3D vector lying in its plane.
+    fn to_2d(&self, v: Vec3) -> (i32, i32) {
+        (dot(v, self.right), dot(v, self.down))
+    }
+}
+
+fn facing((dx, dy): (i32, i32)) -> usize {
+    match (dx, dy) {
+        (1, 0) => 0,
+        (0, 1) => 1,
+        (-1, 0) => 2,
+        (0, -1) => 3,
+        _ => unreachable!(),
+    }
+}
+
+const DIRECTIONS: [(i32, i32); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+/// A cube folded from any of the 11 cube nets, described by a padded map.
+struct Cube {
+    size: usize,
+    faces: Vec<Face>,
+    tile_faces: Vec<Vec<Option<usize>>>,
+    /// For each face and facing, the index of the face glued to that edge.
+    seams: Vec<[usize; 4]>,
+}
+
+impl Cube {
+    /// Fails for a map that is not made of six square faces forming a cube net.
+    fn fold(map: &[Vec<char>]) -> Result<Cube, Error> {
+        let not_a_net = |message| {
+            Err(Error::parse(
+                1,
+                1,
+                format!("the map is not a cube net: {}", message),
+            ))
+        };
+
+        let area = map.iter().flatten().filter(|&&c| c != ' ').count();
+        let size = ((area / 6) as f64).sqrt() as usize;
+        if size == 0 || 6 * size * size != area {
+            return not_a_net("its area is not six squares");
+        }
+
+        let tiles_high = (map.len() - 2) / size;
+        let tiles_wide = (map[0].len() - 2) / size;
+
+        // every tile of the net has to be fully on the map or fully off it
+        let mut full_tiles = 0;
+        for ty in 0..tiles_high {
+            for tx in 0..tiles_wide {
+                let on_map = (0..size)
+                    .flat_map(|y| (0..size).map(move |x| (y, x)))
+                    .filter(|&(y, x)| map[ty * size + y + 1][tx * size + x + 1] != ' ')
+                    .count();
+                if on_map == size * size {
+                    full_tiles += 1;
+                } else if on_map > 0 {
+                    return Err(Error::parse(
+                        ty * size + 1,
+                        tx * size + 1,
+                        format!(
+                            "the map is not a cube net: this {}x{} face is incomplete",
+                            size, size
+                        ),
+                    ));
+                }
+            }
+        }
+        if full_tiles != 6 {
+            return not_a_net("its faces are not aligned to a grid");
+        }
+
+        let occupied = |(ty, tx): (usize, usize)| {
+            ty < tiles_high && tx < tiles_wide && map[ty * size + 1][tx * size + 1] != ' '
+        };
+
+        // walk the net, rolling the cube over each edge we cross
+        let Some(start) = (0..tiles_wide).map(|tx| (0, tx)).find(|&t| occupied(t)) else {
+            return not_a_net("its first row is empty");
+        };
+
+        let mut faces = vec![Face {
+            tile: start,
+            normal: [0, 0, -1],
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+        }];
+        let mut tile_faces = vec![vec![None; tiles_wide]; tiles_high];
+        tile_faces[start.0][start.1] = Some(0);
+
+        let mut i = 0;
+        while i < faces.len() {
+            let (ty, tx) = faces[i].tile;
+            for (dx, dy) in DIRECTIONS {
+                let (ny, nx) = (ty as i32 + dy, tx as i32 + dx);
+                if ny < 0 || nx < 0 {
+                    continue;
+                }
+                let tile = (ny as usize, nx as usize);
+                if !occupied(tile) || tile_faces[tile.0][tile.1].is_some() {
+                    continue;
+                }
+
+                let face = &faces[i];
+                let edge = face.to_3d((dx, dy));
+                let (right, down) = match (dx, dy) {
+                    (1, 0) => (neg(face.normal), face.down),
+                    (-1, 0) => (face.normal, face.down),
+                    (0, 1) => (face.right, neg(face.normal)),
+                    (0, -1) => (face.right, face.normal),
+                    _ => unreachable!(),
+                };
+
+                tile_faces[tile.0][tile.1] = Some(faces.len());
+                faces.push(Face {
+                    tile,
+                    normal: edge,
+                    right,
+                    down,
+                });
+            }
+            i += 1;
+        }
+
+        if faces.len() != 6 {
+            return not_a_net("its faces are not connected");
+        }
+        // with two faces folded onto the same side, another side is left open
+        if (1..6).any(|i| faces[..i].iter().any(|f| f.normal == faces[i].normal)) {
+            return not_a_net("some of its faces overlap when folded");
+        }
+
+        // the face glued to an edge is the one whose normal points the way the edge does,
+        // and all six sides are covered by now
+        let seams = faces
+            .iter()
+            .map(|face| {
+                DIRECTIONS.map(|dir| {
+                    let edge = face.to_3d(dir);
+                    faces
+                        .iter()
+                        .position(|f| f.normal == edge)
+                        .unwrap_or_default()
+                })
+            })
+            .collect();
+
+        Ok(Cube {
+            size,
+            faces,
+            tile_faces,
+            seams,
+        })
+    }
+
+    /// Take one step from (r, c) in the padded map, crossing onto the adjacent
+    /// face (and turning accordingly) when walking off the edge of the net.
+    /// `None` if (r, c) is not on any face.
+    fn step(
+        &self,
+        map: &[Vec<char>],
+        (r, c): (i32, i32),
+        dir: (i32, i32),
+    ) -> Option<((i32, i32), (i32, i32))> {
+        let (nr, nc) = (r + dir.1, c + dir.0);
+        if map[nr as usize][nc as usize] != ' ' {
+            return Some(((nr, nc), dir));
+        }
+
+        let size = self.size as i32;
+        let (y, x) = ((r - 1) as usize, (c - 1) as usize);
+
+        let face = (*self.tile_faces.get(y / self.size)?.get(x / self.size)?)?;
+        let from = &self.faces[face];
+        let to = &self.faces[self.seams[face][facing(dir)]];
+
+        // position of the tile's centre on a cube spanning [-size, size] in every axis
+        let (lx, ly) = ((x % self.size) as i32, (y % self.size) as i32);
+        let edge = from.to_3d(dir);
+        let mut pos = [0; 3];
+        for (i, p) in pos.iter_mut().enumerate() {
+            *p = size * from.normal[i]
+                + (2 * lx - (size - 1)) * from.right[i]
+                + (2 * ly - (size - 1)) * from.down[i];
+            // roll over the edge onto the adjacent face
+            *p += edge[i] - from.normal[i];
+        }
+
+        let (lx, ly) = to.to_2d(pos);
+        let (lx, ly) = ((lx + size - 1) / 2, (ly + size - 1) / 2);
+
+        let r = (to.tile.0 * self.size) as i32 + ly + 1;
+        let c = (to.tile.1 * self.size) as i32 + lx + 1;
+
+        Some(((r, c), to.to_2d(neg(from.normal))))
+    }
+}
+
+pub fn part_two(input: &str) -> Result<i32, Error> {
+    let (map, commands) = parse(input)?;
+    let cube = Cube::fold(&map)?;
+
+    // find starting point, `parse` made sure that the first row has one
+    let Some(start) = map[1].iter().position(|&t| t == '.') else {
+        return Err(Error::parse(
+            1,
+            1,
+            "the first row has no open tile to start on",
+        ));
+    };
+    let mut pos = (1, start as i32);
+    let mut direction = (1, 0);
+
+    for command in commands.iter() {
+        match command {
+            Command::Turn('R') => {
+                direction = (-direction.1, direction.0);
+            }
+            Command::Turn('L') => {
+                direction = (direction.1, -direction.0);
+            }
+            &Command::Forward(steps) => {
+                for _ in 0..steps {
+                    let (next, next_direction) =
+                        cube.step(&map, pos, direction).ok_or_else(|| {
+                            Error::parse(pos.0 as usize, pos.1 as usize, "walked off the cube net")
+                        })?;
+
+                    // check if we would be standing on top of an obstacle ('#')
+                    if map[next.0 as usize][next.1 as usize] == '#' {
+                        break;
+                    }
+                    (pos, direction) = (next, next_direction);
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    Ok(pos.0 * 1000 + pos.1 * 4 + facing(direction) as i32)
+}
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 22);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate test;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 22).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 6032);
+
+        let input = input.replace("R5L5R", "R5LLR");
+        assert!(matches!(
+            part_one(&input),
+            Err(Error::Parse {
+                line: 14,
+                column: 6,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 22).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 5031);
+    }
+
+    #[test]
+    fn test_cube_nets() {
+        const NETS: [&str; 11] = [
+            "#...\n####\n#...",
+            "#...\n####\n.#..",
+            "#...\n####\n..#.",
+            "#...\n####\n...#",
+            ".#..\n####\n.#..",
+            ".#..\n####\n..#.",
+            "##..\n.###\n.#..",
+            "##..\n.###\n..#.",
+            "##..\n.###\n...#",
+            "##..\n.##.\n..##",
+            "###..\n..###",
+        ];
+        const SIZE: usize = 3;
+
+        for net in NETS {
+            // scale up each tile of the net into a SIZE x SIZE face
+            let mut input = String::new();
+            for row in net.lines() {
+                let line = row
+                    .chars()
+                    .map(|t| if t == '#' { "." } else { " " }.repeat(SIZE))
+                    .collect::<String>();
+                for _ in 0..SIZE {
+                    input.push_str(line.trim_end());
+                    input.push('\n');
+                }
+            }
+            input.push_str("\n1");
+
+            let (map, _) = parse(&input).unwrap();
+            let cube = Cube::fold(&map).unwrap();
+
+            // walking 4 edge lengths in a straight line takes us around the cube
+            for (r, row) in map.iter().enumerate() {
+                for (c, &tile) in row.iter().enumerate() {
+                    if tile == ' ' {
+                        continue;
+                    }
+                    for dir in DIRECTIONS {
+                        let start = ((r as i32, c as i32), dir);
+                        let mut state = start;
+                        for _ in 0..4 * SIZE {
+                            state = cube.step(&map, state.0, state.1).unwrap();
+                            assert_ne!(map[state.0 .0 as usize][state.0 .1 as usize], ' ');
+                        }
+                        assert_eq!(state, start, "net:\n{}", net);
+                    }
+                }
+            }
+        }
+
+        // a strip of six faces folds onto itself, the others don't add up to six faces
+        for (map, line, column) in [
+            ("......", 1, 1),
+            ("...\n...\n..", 1, 1),
+            ("..\n..\n..\n..\n..\n..\n..", 1, 1),
+            (".....\n...\n.", 1, 1),
+            (" ..\n..\n...", 1, 1),
+            ("..  .\n.. . ", 1, 1),
+            // the face starting on line 5 misses a tile, which is one too many to the right
+            ("  ..\n  ..\n......\n......\n  . .\n  ..\n  ..\n  ..", 5, 3),
+        ] {
+            let input = format!("{}\n\n1", map);
+            assert!(
+                matches!(
+                    part_two(&input),
+                    Err(Error::Parse { line: l, column: c, .. }) if (l, c) == (line, column)
+                ),
+                "map:\n{}",
+                map
+            );
+        }
+    }
+
+    #[bench]
+    fn bench_part_one(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 22).unwrap();
+        b.iter(|| part_one(input));
+    }
+
+    #[bench]
+    fn bench_part_two(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 22).unwrap();
+        b.iter(|| part_two(input));
+    }
+}
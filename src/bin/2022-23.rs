@@ -1,28 +1,34 @@
 #![feature(test)]
 #![feature(box_patterns)]
-#![feature(box_syntax)]
 
+use advent_of_code::Error;
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet, VecDeque};
 
 type Point = (i32, i32);
 type Map = HashSet<Point>;
 
-fn parse_map(input: &str) -> Map {
-    input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.chars()
-                .enumerate()
-                .filter(|&(_, c)| c == '#')
-                .map(move |(x, _)| (x as i32, -(y as i32)))
-        })
-        .collect()
+fn parse_map(input: &str) -> Result<Map, Error> {
+    let mut map = Map::new();
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            match c {
+                '#' => {
+                    map.insert((x as i32, -(y as i32)));
+                }
+                '.' => {}
+                _ => return Err(Error::parse(y + 1, x + 1, format!("unexpected {:?}", c))),
+            }
+        }
+    }
+    if map.is_empty() {
+        return Err(Error::parse(1, 1, "expected at least one elf"));
+    }
+    Ok(map)
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let mut map = parse_map(input);
+pub fn part_one(input: &str) -> Result<usize, Error> {
+    let mut map = parse_map(input)?;
 
     let check_north = |(x, y), propositions: &mut HashMap<Point, Option<Point>>, map: &Map| {
         let north = (-1..=1).all(|dx| !map.contains(&(x + dx, y + 1)));
@@ -96,10 +102,10 @@ pub fn part_one(input: &str) -> Option<usize> {
         Box<dyn FnMut((i32, i32), &mut HashMap<Point, Option<Point>>, &Map) -> bool>,
     > = VecDeque::new();
 
-    direction_checks.push_back(box check_north);
-    direction_checks.push_back(box check_south);
-    direction_checks.push_back(box check_west);
-    direction_checks.push_back(box check_east);
+    direction_checks.push_back(Box::new(check_north));
+    direction_checks.push_back(Box::new(check_south));
+    direction_checks.push_back(Box::new(check_west));
+    direction_checks.push_back(Box::new(check_east));
 
     let mut propositions = HashMap::with_capacity(map.len());
     for _ in 0..10 {
@@ -146,8 +152,9 @@ pub fn part_one(input: &str) -> Option<usize> {
         direction_checks.push_back(fst);
     }
 
-    // find area of bounding rectangle of all points in the map
-    let (mut min_x, mut max_x, mut min_y, mut max_y) = (0, 0, 0, 0);
+    // find area of bounding rectangle of all points in the map, which has at least one
+    let &(x, y) = map.iter().next().unwrap();
+    let (mut min_x, mut max_x, mut min_y, mut max_y) = (x, x, y, y);
     for &(x, y) in map.iter() {
         if x < min_x {
             min_x = x;
@@ -164,11 +171,11 @@ pub fn part_one(input: &str) -> Option<usize> {
     }
 
     let area = (max_x - min_x + 1) * (max_y - min_y + 1);
-    Some(area as usize - map.len())
+    Ok(area as usize - map.len())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut map = parse_map(input);
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    let mut map = parse_map(input)?;
 
     let check_north = |(x, y), propositions: &mut HashMap<Point, Option<Point>>, map: &Map| {
         let north = (-1..=1).all(|dx| !map.contains(&(x + dx, y + 1)));
@@ -242,10 +249,10 @@ pub fn part_two(input: &str) -> Option<u32> {
         Box<dyn FnMut((i32, i32), &mut HashMap<Point, Option<Point>>, &Map) -> bool>,
     > = VecDeque::new();
 
-    direction_checks.push_back(box check_north);
-    direction_checks.push_back(box check_south);
-    direction_checks.push_back(box check_west);
-    direction_checks.push_back(box check_east);
+    direction_checks.push_back(Box::new(check_north));
+    direction_checks.push_back(Box::new(check_south));
+    direction_checks.push_back(Box::new(check_west));
+    direction_checks.push_back(Box::new(check_east));
 
     let mut propositions = HashMap::with_capacity(map.len());
     let mut turn = 1;
@@ -290,7 +297,7 @@ pub fn part_two(input: &str) -> Option<u32> {
         }
 
         if !any_changed {
-            break Some(turn);
+            break Ok(turn);
         }
 
         propositions.clear();
@@ -304,7 +311,7 @@ pub fn part_two(input: &str) -> Option<u32> {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 23);
+    let input = &advent_of_code::read_input(2022, 23);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -316,32 +323,36 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 23);
-        assert_eq!(part_one(&input), Some(110));
+        let input = advent_of_code::read_file("examples", 2022, 23).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 110);
+        assert_eq!(part_one("#").unwrap(), 0);
+
+        assert!(matches!(
+            part_one("..#\n.x."),
+            Err(Error::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+        assert!(matches!(part_one("...\n"), Err(Error::Parse { .. })));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 23);
-        assert_eq!(part_two(&input), Some(20));
-    }
-
-    #[test]
-    fn test_solution() {
-        let input = advent_of_code::read_file("inputs", 23);
-        assert_eq!(part_one(&input), Some(4049));
-        assert_eq!(part_two(&input), Some(1021));
+        let input = advent_of_code::read_file("examples", 2022, 23).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 20);
     }
 
     #[bench]
     fn bench_part_one(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 23);
+        let input = &advent_of_code::read_file("inputs", 2022, 23).unwrap();
         b.iter(|| part_one(input));
     }
 
     #[bench]
     fn bench_part_two(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", 23);
+        let input = &advent_of_code::read_file("inputs", 2022, 23).unwrap();
         b.iter(|| part_two(input));
     }
 }
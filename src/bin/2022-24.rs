@@ -0,0 +1,225 @@
+#![feature(test)]
+
+use advent_of_code::helpers::Grid;
+use advent_of_code::Error;
+use std::collections::VecDeque;
+
+type Point = (i32, i32);
+
+/// The valley floor (without walls) and its blizzards, one bitset per row or column
+/// for each direction. The blizzards only move along their row or column and wrap
+/// around, so their positions at any time can be computed by rotating the bitsets.
+struct Valley {
+    width: usize,
+    height: usize,
+    /// Bit x of `left[y]` is set if a blizzard moving left started at (x, y).
+    left: Vec<u128>,
+    right: Vec<u128>,
+    /// Bit y of `up[x]` is set if a blizzard moving up started at (x, y).
+    up: Vec<u128>,
+    down: Vec<u128>,
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn parse_valley(input: &str) -> Result<Valley, Error> {
+    let grid = Grid::parse(input, |c| c)?;
+    if grid.width() < 3 || grid.height() < 3 {
+        return Err(Error::parse(1, 1, "expected a valley surrounded by walls"));
+    }
+    let (width, height) = (grid.width() - 2, grid.height() - 2);
+
+    // a blizzard's position along its row or column is a bit in a `u128`
+    let max = u128::BITS as usize;
+    if width > max || height > max {
+        return Err(Error::parse(
+            1,
+            1,
+            format!(
+                "the valley is {}x{}, but at most {}x{} is supported",
+                width, height, max, max
+            ),
+        ));
+    }
+
+    let mut valley = Valley {
+        width,
+        height,
+        left: vec![0; height],
+        right: vec![0; height],
+        up: vec![0; width],
+        down: vec![0; width],
+    };
+
+    for y in 0..height {
+        for x in 0..width {
+            match grid[(x + 1, y + 1)] {
+                '<' => valley.left[y] |= 1 << x,
+                '>' => valley.right[y] |= 1 << x,
+                '^' => valley.up[x] |= 1 << y,
+                'v' => valley.down[x] |= 1 << y,
+                '.' => {}
+                c => {
+                    return Err(Error::parse(
+                        y + 2,
+                        x + 2,
+                        format!("{:?} is neither ground nor a blizzard", c),
+                    ))
+                }
+            }
+        }
+    }
+
+    Ok(valley)
+}
+
+impl Valley {
+    fn start(&self) -> Point {
+        (0, -1)
+    }
+
+    fn end(&self) -> Point {
+        (self.width as i32 - 1, self.height as i32)
+    }
+
+    /// Number of minutes after which all blizzards are back in their initial positions.
+    fn period(&self) -> usize {
+        self.width * self.height / gcd(self.width, self.height)
+    }
+
+    /// Is (x, y) free of blizzards at time `t`?
+    fn is_clear(&self, (x, y): Point, t: usize) -> bool {
+        if (x, y) == self.start() || (x, y) == self.end() {
+            return true;
+        }
+        if x < 0 || y < 0 || x >= self.width as i32 || y >= self.height as i32 {
+            return false;
+        }
+
+        let (x, y) = (x as usize, y as usize);
+        let (w, h) = (self.width, self.height);
+
+        // a blizzard is at (x, y) at time t if it started t steps behind
+        let left = (x + t) % w;
+        let right = (x + w - t % w) % w;
+        let up = (y + t) % h;
+        let down = (y + h - t % h) % h;
+
+        (self.left[y] >> left) & 1 == 0
+            && (self.right[y] >> right) & 1 == 0
+            && (self.up[x] >> up) & 1 == 0
+            && (self.down[x] >> down) & 1 == 0
+    }
+
+    fn index(&self, (x, y): Point) -> usize {
+        if (x, y) == self.start() {
+            self.width * self.height
+        } else if (x, y) == self.end() {
+            self.width * self.height + 1
+        } else {
+            y as usize * self.width + x as usize
+        }
+    }
+
+    /// Breadth-first search over (position, time mod period) for the earliest
+    /// arrival at `to` when leaving `from` at time `start`.
+    fn crossing(&self, from: Point, to: Point, start: usize) -> Option<usize> {
+        let period = self.period();
+        let cells = self.width * self.height + 2;
+
+        let mut visited = vec![false; cells * period];
+        let mut queue = VecDeque::new();
+
+        visited[self.index(from) * period + start % period] = true;
+        queue.push_back((from, start));
+
+        while let Some(((x, y), t)) = queue.pop_front() {
+            if (x, y) == to {
+                return Some(t);
+            }
+
+            for next in [(x, y), (x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
+                if !self.is_clear(next, t + 1) {
+                    continue;
+                }
+
+                let key = self.index(next) * period + (t + 1) % period;
+                if !visited[key] {
+                    visited[key] = true;
+                    queue.push_back((next, t + 1));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+pub fn part_one(input: &str) -> Result<usize, Error> {
+    let valley = parse_valley(input)?;
+    valley
+        .crossing(valley.start(), valley.end(), 0)
+        .ok_or(Error::Unsolved)
+}
+
+pub fn part_two(input: &str) -> Result<usize, Error> {
+    let valley = parse_valley(input)?;
+    let (start, end) = (valley.start(), valley.end());
+
+    let trip = || {
+        let there = valley.crossing(start, end, 0)?;
+        let back = valley.crossing(end, start, there)?;
+        valley.crossing(start, end, back)
+    };
+    trip().ok_or(Error::Unsolved)
+}
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 24);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate test;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 24).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 18);
+        assert!(matches!(
+            part_one("#.###\n#.x.#\n###.#"),
+            Err(Error::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 24).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 54);
+    }
+
+    #[bench]
+    fn bench_part_one(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 24).unwrap();
+        b.iter(|| part_one(input));
+    }
+
+    #[bench]
+    fn bench_part_two(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 24).unwrap();
+        b.iter(|| part_two(input));
+    }
+}
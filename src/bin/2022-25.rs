@@ -0,0 +1,435 @@
+#![feature(test)]
+
+use advent_of_code::Error;
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+use z3::ast::Ast;
+
+/// An arbitrary-length number in balanced base 5, with digits -2..=2.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+struct Snafu {
+    /// Least significant digit first, without leading (most significant) zeros.
+    digits: Vec<i8>,
+}
+
+/// The digit that failed to parse and its 0-based position in the string.
+#[derive(Debug, PartialEq, Eq)]
+struct InvalidDigit(char, usize);
+
+#[derive(Debug, PartialEq, Eq)]
+struct Overflow;
+
+impl Snafu {
+    /// Bring arbitrary digit values back into -2..=2 by carrying into higher digits.
+    fn normalize(digits: impl IntoIterator<Item = i64>) -> Self {
+        let mut normalized = Vec::new();
+        let mut carry = 0;
+
+        for d in digits {
+            let value = d + carry;
+            let digit = (value + 2).rem_euclid(5) - 2;
+            carry = (value - digit) / 5;
+            normalized.push(digit as i8);
+        }
+
+        while carry != 0 {
+            let digit = (carry + 2).rem_euclid(5) - 2;
+            carry = (carry - digit) / 5;
+            normalized.push(digit as i8);
+        }
+
+        while normalized.last() == Some(&0) {
+            normalized.pop();
+        }
+
+        Snafu { digits: normalized }
+    }
+
+    fn signum(&self) -> i8 {
+        self.digits.last().map_or(0, |d| d.signum())
+    }
+}
+
+impl FromStr for Snafu {
+    type Err = InvalidDigit;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s
+            .char_indices()
+            .rev()
+            .map(|(i, c)| match c {
+                '0' => Ok(0),
+                '1' => Ok(1),
+                '2' => Ok(2),
+                '-' => Ok(-1),
+                '=' => Ok(-2),
+                _ => Err(InvalidDigit(c, s[..i].chars().count())),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Snafu::normalize(digits))
+    }
+}
+
+impl fmt::Display for Snafu {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.digits.is_empty() {
+            return write!(f, "0");
+        }
+        for d in self.digits.iter().rev() {
+            let c = match d {
+                0 => '0',
+                1 => '1',
+                2 => '2',
+                -1 => '-',
+                -2 => '=',
+                _ => unreachable!(),
+            };
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<i64> for Snafu {
+    fn from(decimal: i64) -> Self {
+        let mut digits = Vec::new();
+        let mut n = decimal as i128;
+
+        while n != 0 {
+            let digit = (n + 2).rem_euclid(5) - 2;
+            n = (n - digit) / 5;
+            digits.push(digit as i8);
+        }
+
+        Snafu { digits }
+    }
+}
+
+impl TryFrom<Snafu> for i64 {
+    type Error = Overflow;
+
+    fn try_from(snafu: Snafu) -> Result<Self, Self::Error> {
+        let mut decimal: i64 = 0;
+        let mut power: Option<i64> = Some(1);
+
+        for &d in snafu.digits.iter() {
+            let term = power
+                .ok_or(Overflow)?
+                .checked_mul(d as i64)
+                .ok_or(Overflow)?;
+            decimal = decimal.checked_add(term).ok_or(Overflow)?;
+            power = power.and_then(|p| p.checked_mul(5));
+        }
+
+        Ok(decimal)
+    }
+}
+
+impl Neg for Snafu {
+    type Output = Snafu;
+
+    fn neg(self) -> Self::Output {
+        Snafu {
+            digits: self.digits.into_iter().map(|d| -d).collect(),
+        }
+    }
+}
+
+impl Add for Snafu {
+    type Output = Snafu;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let len = self.digits.len().max(rhs.digits.len());
+        Snafu::normalize((0..len).map(|i| {
+            *self.digits.get(i).unwrap_or(&0) as i64 + *rhs.digits.get(i).unwrap_or(&0) as i64
+        }))
+    }
+}
+
+impl Sub for Snafu {
+    type Output = Snafu;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul for Snafu {
+    type Output = Snafu;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.digits.is_empty() || rhs.digits.is_empty() {
+            return Snafu::default();
+        }
+
+        let mut product = vec![0_i64; self.digits.len() + rhs.digits.len()];
+        for (i, &a) in self.digits.iter().enumerate() {
+            for (j, &b) in rhs.digits.iter().enumerate() {
+                product[i + j] += a as i64 * b as i64;
+            }
+        }
+
+        Snafu::normalize(product)
+    }
+}
+
+impl Sum for Snafu {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Snafu::default(), |acc, x| acc + x)
+    }
+}
+
+impl Ord for Snafu {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.clone() - other.clone()).signum().cmp(&0)
+    }
+}
+
+impl PartialOrd for Snafu {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Parse a single line of SNAFU, reporting errors as if it were the first line.
+fn snafu_to_decimal(snafu: &str) -> Result<i64, Error> {
+    let parsed = snafu.parse::<Snafu>().map_err(|InvalidDigit(c, i)| {
+        Error::parse(1, i + 1, format!("invalid SNAFU digit {:?}", c))
+    })?;
+    parsed
+        .try_into()
+        .map_err(|Overflow| Error::parse(1, 1, format!("{:?} does not fit in an i64", snafu)))
+}
+
+/// Convert to SNAFU by asking Z3 for the digits. Only used to cross-check [`Snafu`].
+fn decimal_to_snafu(decimal: i64) -> String {
+    let cfg = z3::Config::new();
+    let ctx = z3::Context::new(&cfg);
+    let solver = z3::Solver::new(&ctx);
+
+    if decimal == 0 {
+        return "0".to_string();
+    }
+
+    // Enough digits for the magnitude, also when the number is negative
+    let max_power_of_5 = (decimal.unsigned_abs() as f64).log(5.0).ceil() as i64 + 1;
+
+    let mut vars = vec![];
+    let mut sum_expr = z3::ast::Int::from_i64(&ctx, 0);
+
+    for i in 0..=max_power_of_5 {
+        let var = z3::ast::Int::new_const(&ctx, format!("power_{}", i));
+
+        solver.assert(&var.ge(&z3::ast::Int::from_i64(&ctx, -2)));
+        solver.assert(&var.le(&z3::ast::Int::from_i64(&ctx, 2)));
+
+        sum_expr += &var * z3::ast::Int::from_i64(&ctx, 5_i64.pow(i as u32));
+
+        vars.push(var);
+    }
+
+    solver.assert(&sum_expr._eq(&z3::ast::Int::from_i64(&ctx, decimal)));
+
+    let mut result = String::new();
+
+    if let z3::SatResult::Sat = solver.check() {
+        let model = solver.get_model().unwrap();
+
+        for v in vars.iter().rev() {
+            let value = model.eval(v, true).unwrap().as_i64().unwrap();
+            match value {
+                0 => result.push('0'),
+                1 => result.push('1'),
+                2 => result.push('2'),
+                -1 => result.push('-'),
+                -2 => result.push('='),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    result.chars().skip_while(|&x| x == '0').collect()
+}
+
+pub fn part_one(input: &str) -> Result<String, Error> {
+    let sum = input
+        .lines()
+        .enumerate()
+        .map(|(row, line)| {
+            line.parse::<Snafu>().map_err(|InvalidDigit(c, i)| {
+                Error::parse(row + 1, i + 1, format!("invalid SNAFU digit {:?}", c))
+            })
+        })
+        .sum::<Result<Snafu, _>>()?;
+    Ok(sum.to_string())
+}
+
+/// Day 25 has no second puzzle, its star comes with all the others.
+pub fn part_two(_input: &str) -> Result<String, Error> {
+    Err(Error::Unsolved)
+}
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 25);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+
+    // `cargo solve 25 -- --cross-check` verifies part one with Z3
+    if std::env::args().any(|arg| arg == "--cross-check") {
+        if let Ok(input) = input {
+            let sum = input
+                .lines()
+                .enumerate()
+                .try_fold(0_i64, |sum, (row, line)| {
+                    let decimal = snafu_to_decimal(line).map_err(|e| e.on_line(row + 1))?;
+                    sum.checked_add(decimal)
+                        .ok_or_else(|| Error::parse(row + 1, 1, "the sum does not fit in an i64"))
+                });
+            match sum {
+                Ok(sum) => {
+                    let (native, z3) = (Snafu::from(sum).to_string(), decimal_to_snafu(sum));
+                    println!(
+                        "{} (native), {} (z3){}",
+                        native,
+                        z3,
+                        if native == z3 { "" } else { " MISMATCH" }
+                    );
+                }
+                Err(e) => println!("cross-check failed: {}", e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate test;
+
+    #[test]
+    fn test_snafu_to_decimal() {
+        assert_eq!(snafu_to_decimal("1=-0-2").unwrap(), 1747);
+        assert_eq!(snafu_to_decimal("12111").unwrap(), 906);
+        assert_eq!(snafu_to_decimal("2=0=").unwrap(), 198);
+        assert_eq!(snafu_to_decimal("21").unwrap(), 11);
+        assert_eq!(snafu_to_decimal("2=01").unwrap(), 201);
+        assert_eq!(snafu_to_decimal("111").unwrap(), 31);
+        assert_eq!(snafu_to_decimal("20012").unwrap(), 1257);
+        assert_eq!(snafu_to_decimal("112").unwrap(), 32);
+        assert_eq!(snafu_to_decimal("1=-1=").unwrap(), 353);
+        assert_eq!(snafu_to_decimal("1-12").unwrap(), 107);
+        assert_eq!(snafu_to_decimal("12").unwrap(), 7);
+        assert_eq!(snafu_to_decimal("1=").unwrap(), 3);
+        assert_eq!(snafu_to_decimal("122").unwrap(), 37);
+        assert!(matches!(
+            snafu_to_decimal("1=x"),
+            Err(Error::Parse {
+                line: 1,
+                column: 3,
+                ..
+            })
+        ));
+        assert!(matches!(
+            snafu_to_decimal("2222222222222222222222222222"),
+            Err(Error::Parse { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_decimal_to_snafu() {
+        assert_eq!(decimal_to_snafu(0), Snafu::from(0).to_string());
+        assert_eq!(decimal_to_snafu(0), "0".to_string());
+        assert_eq!(decimal_to_snafu(-3), Snafu::from(-3).to_string());
+        assert_eq!(decimal_to_snafu(1), "1".to_string());
+        assert_eq!(decimal_to_snafu(2), "2".to_string());
+        assert_eq!(decimal_to_snafu(3), "1=".to_string());
+        assert_eq!(decimal_to_snafu(4), "1-".to_string());
+        assert_eq!(decimal_to_snafu(5), "10".to_string());
+        assert_eq!(decimal_to_snafu(6), "11".to_string());
+        assert_eq!(decimal_to_snafu(7), "12".to_string());
+        assert_eq!(decimal_to_snafu(8), "2=".to_string());
+        assert_eq!(decimal_to_snafu(9), "2-".to_string());
+        assert_eq!(decimal_to_snafu(10), "20".to_string());
+        assert_eq!(decimal_to_snafu(15), "1=0".to_string());
+        assert_eq!(decimal_to_snafu(20), "1-0".to_string());
+        assert_eq!(decimal_to_snafu(2022), "1=11-2".to_string());
+        assert_eq!(decimal_to_snafu(12345), "1-0---0".to_string());
+        assert_eq!(decimal_to_snafu(314159265), "1121-1110-1=0".to_string());
+    }
+
+    #[test]
+    fn test_snafu_roundtrip() {
+        for n in [0, 1, -1, 2022, -2022, 314159265, i64::MAX, i64::MIN] {
+            let snafu = Snafu::from(n);
+            assert_eq!(snafu.to_string().parse::<Snafu>(), Ok(snafu.clone()));
+            assert_eq!(i64::try_from(snafu), Ok(n));
+        }
+        assert_eq!(Snafu::from(0).to_string(), "0");
+        assert_eq!("00012".parse::<Snafu>(), Ok(Snafu::from(7)));
+        assert_eq!("1x".parse::<Snafu>(), Err(InvalidDigit('x', 1)));
+    }
+
+    #[test]
+    fn test_snafu_arithmetic() {
+        let values = [-1747, -31, -3, 0, 1, 2, 7, 198, 906, 12345];
+        for a in values {
+            for b in values {
+                let (sa, sb) = (Snafu::from(a), Snafu::from(b));
+                assert_eq!(sa.clone() + sb.clone(), Snafu::from(a + b));
+                assert_eq!(sa.clone() - sb.clone(), Snafu::from(a - b));
+                assert_eq!(sa.clone() * sb.clone(), Snafu::from(a * b));
+                assert_eq!(sa.cmp(&sb), a.cmp(&b));
+            }
+            assert_eq!(-Snafu::from(a), Snafu::from(-a));
+        }
+    }
+
+    #[test]
+    fn test_snafu_overflow() {
+        let max = Snafu::from(i64::MAX);
+        assert_eq!(i64::try_from(max.clone() + Snafu::from(1)), Err(Overflow));
+        assert_eq!(
+            i64::try_from(max.clone() * Snafu::from(5) - max),
+            Err(Overflow)
+        );
+        assert_eq!(
+            (Snafu::from(i64::MAX) * Snafu::from(i64::MAX)).to_string(),
+            "2==2=-01102--1-0=0-=012122-100--001=000-12-10201002000-"
+        );
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 25).unwrap();
+        assert_eq!(part_one(&input).unwrap(), "2=-1=0");
+        assert!(matches!(
+            part_one("1=\n1x"),
+            Err(Error::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 25).unwrap();
+        assert!(matches!(part_two(&input), Err(Error::Unsolved)));
+    }
+
+    #[bench]
+    fn bench_part_one(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 25).unwrap();
+        b.iter(|| part_one(input));
+    }
+
+    #[bench]
+    fn bench_part_two(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", 2022, 25).unwrap();
+        b.iter(|| part_two(input));
+    }
+}
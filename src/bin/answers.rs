@@ -0,0 +1,155 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::{self, Manifest, Verdict};
+use std::process;
+
+struct Args {
+    day: Option<u8>,
+    part: Option<u8>,
+    year: u16,
+    profile: String,
+    correct: Option<String>,
+    wrong: Option<String>,
+    check: Option<String>,
+    force: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(advent_of_code::default_year),
+        profile: args
+            .opt_value_from_str(["-p", "--profile"])?
+            .unwrap_or_else(|| answers::DEFAULT_PROFILE.to_string()),
+        correct: args.opt_value_from_str("--correct")?,
+        wrong: args.opt_value_from_str("--wrong")?,
+        check: args.opt_value_from_str("--check")?,
+        force: args.contains("--force"),
+        day: args.opt_free_from_str()?,
+        part: args.opt_free_from_str()?,
+    })
+}
+
+fn save(manifest: &Manifest) {
+    if let Err(e) = manifest.save(answers::MANIFEST_PATH) {
+        eprintln!("Failed to write \"{}\": {}", answers::MANIFEST_PATH, e);
+        process::exit(1);
+    }
+}
+
+/// Print what is known about every part of the profile in `year`.
+fn list(manifest: &Manifest, profile: &str, year: u16) {
+    for day in 1..=25 {
+        let Some(expected) = manifest.get(profile, year, day) else {
+            continue;
+        };
+        for part in 1..=2 {
+            let wrong = expected.wrong(part);
+            if expected.part(part).is_none() && wrong.is_empty() {
+                continue;
+            }
+            println!(
+                "Day {:02} part {}: {}{}",
+                day,
+                part,
+                expected.part(part).unwrap_or("?"),
+                if wrong.is_empty() {
+                    String::new()
+                } else {
+                    format!(" (wrong: {})", wrong.join(", "))
+                }
+            );
+        }
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    // a missing file just means that nothing is known yet
+    let mut manifest = match Manifest::load(answers::MANIFEST_PATH) {
+        Ok(manifest) => manifest,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Manifest::default(),
+        Err(e) => {
+            eprintln!("Failed to load \"{}\": {}", answers::MANIFEST_PATH, e);
+            process::exit(1);
+        }
+    };
+
+    let (day, part) = match (args.day, args.part) {
+        (None, _) => {
+            list(&manifest, &args.profile, args.year);
+            return;
+        }
+        (Some(day), Some(part @ 1..=2)) => (day, part),
+        _ => {
+            eprintln!("Need to specify a day and a part (1 or 2). example: `cargo answers 7 1`");
+            process::exit(1);
+        }
+    };
+
+    let expected = manifest.entry(&args.profile, args.year, day);
+
+    if let Some(answer) = args.correct {
+        if expected.wrong(part).contains(&answer) {
+            eprintln!(
+                "\"{}\" was rejected before. Remove it from \"{}\" if that was a mistake.",
+                answer,
+                answers::MANIFEST_PATH
+            );
+            process::exit(1);
+        }
+        if let Some(recorded) = expected.part(part).filter(|&r| r != answer && !args.force) {
+            eprintln!(
+                "\"{}\" is already recorded as the answer to day {:02} part {}. Pass --force to replace it.",
+                recorded, day, part
+            );
+            process::exit(1);
+        }
+        expected.set_part(part, &answer);
+        save(&manifest);
+        println!(
+            "🎄 Recorded \"{}\" as the answer to day {:02} part {}.",
+            answer, day, part
+        );
+    } else if let Some(answer) = args.wrong {
+        if expected.part(part) == Some(answer.as_str()) {
+            eprintln!("\"{}\" is recorded as the correct answer.", answer);
+            process::exit(1);
+        }
+        if expected.add_wrong(part, &answer) {
+            save(&manifest);
+            println!(
+                "Recorded \"{}\" as a wrong answer to day {:02} part {}.",
+                answer, day, part
+            );
+        } else {
+            println!("\"{}\" was already recorded as wrong.", answer);
+        }
+    } else if let Some(answer) = args.check {
+        let verdict = expected.verify(part, &answer);
+        match verdict {
+            Verdict::Verified => println!("{} \"{}\" is correct.", verdict, answer),
+            Verdict::Mismatch => println!("{} \"{}\" is wrong, don't submit it.", verdict, answer),
+            Verdict::Unknown => println!("{} \"{}\" has not been tried yet.", verdict, answer),
+        }
+        if verdict == Verdict::Mismatch {
+            process::exit(1);
+        }
+    } else {
+        println!("Answer: {}", expected.part(part).unwrap_or("?"));
+        if !expected.wrong(part).is_empty() {
+            println!("Wrong: {}", expected.wrong(part).join(", "));
+        }
+    }
+}
@@ -2,104 +2,89 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::io::Write;
-use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
-use std::{fs, process};
+use advent_of_code::client::{self, Client};
+use std::path::Path;
+use std::{env, process};
 
 struct Args {
     day: u8,
-    year: Option<i16>,
+    year: u16,
+    base_url: String,
+    refresh: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(advent_of_code::default_year),
+        base_url: args
+            .opt_value_from_str("--base-url")?
+            .or_else(|| env::var("AOC_BASE_URL").ok())
+            .unwrap_or_else(|| client::DEFAULT_BASE_URL.to_string()),
+        refresh: args.contains("--refresh"),
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
     })
 }
 
-fn remove_file(path: &PathBuf) {
-    #[allow(unused_must_use)]
-    {
-        fs::remove_file(path);
+fn save(path: &str, contents: &str) {
+    let path = Path::new(path);
+    match client::write_atomically(path, contents) {
+        Ok(_) => println!("🎄 Successfully wrote \"{}\".", path.display()),
+        Err(e) => {
+            eprintln!("Failed to write \"{}\": {}", path.display(), e);
+            process::exit(1);
+        }
     }
 }
 
-fn exit_with_status(status: i32, path: &PathBuf) -> ! {
-    remove_file(path);
-    process::exit(status);
-}
-
 fn main() {
-    // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
-    let mut tmp_file_path = temp_dir();
-    tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
-
     let args = match parse_args() {
         Ok(args) => args,
         Err(e) => {
             eprintln!("Failed to process arguments: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-
-    // check if aoc binary exists and is callable.
-    if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        exit_with_status(1, &tmp_file_path);
+    // there is nothing to download for other days, don't bother the server
+    if !(1..=25).contains(&args.day) {
+        eprintln!("{} is not a day between 1 and 25.", args.day);
+        process::exit(1);
     }
 
-    let mut cmd_args = vec![];
+    let session = match client::find_session() {
+        Ok(session) => session,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
 
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
+    let client = Client::new(&args.base_url, &session)
+        .with_cache(client::CACHE_DIR)
+        .refresh(args.refresh);
 
-    cmd_args.append(&mut vec![
-        "--input-file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "download".into(),
-    ]);
+    let day_padded = format!("{:02}", args.day);
+    let input_path = format!("src/inputs/{}/{}.txt", args.year, day_padded);
+    let puzzle_path = format!("src/puzzles/{}/{}.html", args.year, day_padded);
 
-    println!("Downloading input with >aoc {}", cmd_args.join(" "));
+    println!("Downloading day {}, {}...", args.day, args.year);
 
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
-            }
-        }
+    match client.input(args.year, args.day) {
+        Ok(input) => save(&input_path, &input),
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("Failed to download input: {}", e);
+            process::exit(1);
         }
     }
 
-    match fs::copy(&tmp_file_path, &input_path) {
-        Ok(_) => {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            exit_with_status(0, &tmp_file_path);
-        }
+    match client.puzzle(args.year, args.day) {
+        Ok(puzzle) => save(&puzzle_path, &puzzle),
         Err(e) => {
-            eprintln!("could not copy downloaded input to input file: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("Failed to download puzzle description: {}", e);
+            process::exit(1);
         }
     }
 }
@@ -0,0 +1,107 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+// unstable features used by the individual solutions
+#![feature(binary_heap_into_iter_sorted)]
+#![feature(iter_array_chunks)]
+#![feature(iter_intersperse)]
+
+use advent_of_code::{readme, InputSource};
+use std::{fs, process};
+
+#[path = "../days.rs"]
+mod days;
+
+const README_PATH: &str = "README.md";
+
+struct Args {
+    bench: usize,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        bench: args.opt_value_from_str("--bench")?.unwrap_or(10),
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(advent_of_code::default_year),
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let source = InputSource::from_env(args.year);
+    if !matches!(source, InputSource::Dir(_)) {
+        eprintln!("`--input` must be a directory with one NN.txt file per day.");
+        process::exit(1);
+    }
+
+    let contents = match fs::read_to_string(README_PATH) {
+        Ok(contents) => contents,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", README_PATH, e);
+            process::exit(1);
+        }
+    };
+
+    // days without an input keep whatever the table said before
+    let previous = readme::parse_table(&contents);
+
+    let rows = days::all()
+        .iter()
+        .filter(|solution| solution.year() == args.year)
+        .map(|solution| {
+            let day = solution.day();
+            let cells = match source.read(day) {
+                Ok(input) => [1, 2].map(|part| {
+                    let (result, stats) = solution.bench(part, &input, args.bench);
+                    match result.answer {
+                        Some(_) => readme::format_cell(&stats),
+                        None => String::new(),
+                    }
+                }),
+                Err(e) => {
+                    println!("Day {:02}: {}, keeping its previous timings.", day, e);
+                    previous.get(&day).cloned().unwrap_or_default()
+                }
+            };
+
+            (day, cells)
+        })
+        .collect::<Vec<_>>();
+
+    let table = readme::render_table(args.year, &rows);
+
+    let updated = match readme::replace_table(&contents, &table) {
+        Some(updated) => updated,
+        None => {
+            eprintln!(
+                "Could not find the \"{}\" and \"{}\" markers in \"{}\".",
+                readme::TABLE_START,
+                readme::TABLE_END,
+                README_PATH
+            );
+            process::exit(1);
+        }
+    };
+
+    match fs::write(README_PATH, updated) {
+        Ok(_) => {
+            println!("---");
+            println!("🎄 Successfully updated the table in \"{}\".", README_PATH);
+        }
+        Err(e) => {
+            eprintln!("Failed to write \"{}\": {}", README_PATH, e);
+            process::exit(1);
+        }
+    }
+}
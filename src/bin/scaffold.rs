@@ -2,93 +2,136 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::puzzle;
+use advent_of_code::template::{self, Module, Returns};
+use regex::Regex;
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::{Path, PathBuf},
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"#![feature(test)]
-
-pub fn part_one(input: &str) -> Option<u32> {
-    None
-}
-
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+struct Args {
+    day: u8,
+    year: u16,
+    /// Saved puzzle description to take the example from.
+    puzzle: Option<PathBuf>,
+    template: Option<PathBuf>,
+    returns: Returns,
+    regex: Option<String>,
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        puzzle: args.opt_value_from_str("--puzzle")?,
+        template: args.opt_value_from_str("--template")?,
+        returns: args.opt_value_from_str("--returns")?.unwrap_or_default(),
+        regex: args.opt_value_from_str("--regex")?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(advent_of_code::default_year),
+        day: args.free_from_str()?,
+    })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    extern crate test;
-
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
-    }
-
-    #[test]
-    fn test_solution() {
-        let input = advent_of_code::read_file("inputs", DAY);
-        assert_eq!(part_one(&input), None);
-        assert_eq!(part_two(&input), None);
-    }
-
-    #[bench]
-    fn bench_part_one(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", DAY);
-        b.iter(|| part_one(input));
-    }
-
-    #[bench]
-    fn bench_part_two(b: &mut test::Bencher) {
-        let input = &advent_of_code::read_file("inputs", DAY);
-        b.iter(|| part_two(input));
+fn create_parent(path: &str) -> Result<(), std::io::Error> {
+    match Path::new(path).parent() {
+        Some(dir) => fs::create_dir_all(dir),
+        None => Ok(()),
     }
 }
-"###;
-
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
-}
 
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+    create_parent(path)?;
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
+    create_parent(path)?;
     OpenOptions::new().write(true).create(true).open(path)
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!("example: `cargo scaffold 7 --returns u64`");
+            process::exit(1);
+        }
+    };
+
+    let regex = match args.regex.as_deref().map(Regex::new).transpose() {
+        Ok(Some(re)) if re.captures_len() == 1 => {
+            eprintln!("The regex needs at least one capture group to parse.");
+            process::exit(1);
+        }
+        Ok(regex) => regex,
+        Err(e) => {
+            eprintln!("Invalid regex: {}", e);
             process::exit(1);
         }
     };
 
+    // an explicitly given template has to exist, the default one may have been deleted
+    let template = match &args.template {
+        Some(path) => match fs::read_to_string(path) {
+            Ok(template) => template,
+            Err(e) => {
+                eprintln!("Failed to read template \"{}\": {}", path.display(), e);
+                process::exit(1);
+            }
+        },
+        None => fs::read_to_string(template::TEMPLATE_PATH)
+            .unwrap_or_else(|_| template::DEFAULT_TEMPLATE.to_string()),
+    };
+
+    let day = args.day;
+    let year = args.year;
     let day_padded = format!("{:02}", day);
+    let bin_name = advent_of_code::bin_name(year, day);
+
+    let input_path = format!("src/inputs/{}/{}.txt", year, day_padded);
+    let example_path = format!("src/examples/{}/{}.txt", year, day_padded);
+    let module_path = format!("src/bin/{}.rs", bin_name);
+
+    // `cargo download` saves the puzzle description here
+    let puzzle_path = args
+        .puzzle
+        .unwrap_or_else(|| format!("src/puzzles/{}/{}.html", year, day_padded).into());
+    let puzzle = fs::read_to_string(&puzzle_path).ok();
+    if puzzle.is_some() {
+        println!("Using puzzle description \"{}\"", puzzle_path.display());
+    }
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let example = puzzle.as_deref().and_then(puzzle::example_input);
+    let examples = puzzle
+        .as_deref()
+        .map(puzzle::example_answers)
+        .unwrap_or_default();
+
+    for (part, answer) in ["one", "two"].iter().zip(examples.iter()) {
+        if let Some(answer) = answer
+            .as_ref()
+            .filter(|a| args.returns.literal(a).is_none())
+        {
+            println!(
+                "Example answer \"{}\" is not a {}, fill in `test_part_{}` by hand or pass `--returns`.",
+                answer, args.returns, part
+            );
+        }
+    }
+
+    let module = Module {
+        day,
+        year,
+        returns: args.returns,
+        regex,
+        examples,
+    }
+    .render(&template);
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -98,7 +141,7 @@ fn main() {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    match file.write_all(module.as_bytes()) {
         Ok(_) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -118,9 +161,16 @@ fn main() {
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    let created = match &example {
+        Some(example) => create_parent(&example_path)
+            .and_then(|_| fs::write(&example_path, example))
+            .map(|_| "example file"),
+        None => create_file(&example_path).map(|_| "empty example file"),
+    };
+
+    match created {
+        Ok(kind) => {
+            println!("Created {} \"{}\"", kind, example_path);
         }
         Err(e) => {
             eprintln!("Failed to create example file: {}", e);
@@ -128,9 +178,32 @@ fn main() {
         }
     }
 
+    let registered = fs::read_to_string(template::REGISTRY_PATH)
+        .map_err(|e| e.to_string())
+        .and_then(|registry| template::register(&registry, year, day))
+        .and_then(|registry| {
+            fs::write(template::REGISTRY_PATH, registry).map_err(|e| e.to_string())
+        });
+    match registered {
+        Ok(_) => println!("Registered the day in \"{}\"", template::REGISTRY_PATH),
+        Err(e) => println!(
+            "Could not register the day ({}), add \"{}\" to \"{}\" by hand to include it in `cargo all`.",
+            e,
+            module_path,
+            template::REGISTRY_PATH
+        ),
+    }
+
     println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    if year == advent_of_code::default_year() {
+        println!(
+            "🎄 Type `cargo solve {}` to run your solution.",
+            &day_padded
+        );
+    } else {
+        println!(
+            "🎄 Type `cargo solve {} --year {}` to run your solution.",
+            day_padded, year
+        );
+    }
 }
@@ -0,0 +1,62 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::ffi::OsString;
+use std::{env, process};
+
+struct Args {
+    day: u8,
+    year: u16,
+    release: bool,
+    /// Cargo features to build the day with, e.g. `alloc-stats`.
+    features: Option<String>,
+    /// Passed on to the day's binary, e.g. `--json` or `--input <path>`.
+    rest: Vec<OsString>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(advent_of_code::default_year),
+        release: args.contains("--release"),
+        features: args.opt_value_from_str("--features")?,
+        day: args.free_from_str()?,
+        rest: args.finish(),
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!("example: `cargo solve 7 --year 2021 --release`");
+            process::exit(1);
+        }
+    };
+
+    // `cargo` sets this for the processes it runs
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let bin_name = advent_of_code::bin_name(args.year, args.day);
+
+    let mut command = process::Command::new(cargo);
+    command.args(["run", "--quiet", "--bin", &bin_name]);
+    if args.release {
+        command.arg("--release");
+    }
+    if let Some(features) = &args.features {
+        command.args(["--features", features]);
+    }
+    command.arg("--").args(&args.rest);
+
+    match command.status() {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(e) => {
+            eprintln!("Failed to run `cargo run --bin {}`: {}", bin_name, e);
+            process::exit(1);
+        }
+    }
+}
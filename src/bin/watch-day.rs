@@ -0,0 +1,198 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::watch::{TestSummary, Watcher};
+use advent_of_code::{
+    PartResult, Status, ANSI_BOLD, ANSI_GREEN, ANSI_ITALIC, ANSI_RED, ANSI_RESET,
+};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::{self, Command, Output};
+use std::time::{Duration, Instant};
+use std::{env, thread};
+
+struct Args {
+    day: u8,
+    year: u16,
+    /// Run the solution (not the tests) with optimizations.
+    release: bool,
+    /// How often to check the files for changes, in milliseconds.
+    interval: u64,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(advent_of_code::default_year),
+        release: args.contains("--release"),
+        interval: args.opt_value_from_str("--interval")?.unwrap_or(500),
+        day: args.free_from_str()?,
+    })
+}
+
+fn cargo(args: &[&str]) -> std::io::Result<Output> {
+    // `cargo` sets this for the processes it runs
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    Command::new(cargo).args(args).output()
+}
+
+/// Run the example tests. Returns `false` if they didn't build.
+fn run_tests(bin_name: &str) -> bool {
+    let started = Instant::now();
+    // benches read the real input, which is what the solution run is for
+    let output = match cargo(&["test", "--bin", bin_name, "--", "--skip", "bench_"]) {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run `cargo test`: {}", e);
+            return false;
+        }
+    };
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    match TestSummary::parse(&stdout) {
+        Some(summary) if summary.is_ok() => {
+            println!(
+                "{}tests ok{}: {} passed {}({:.2?}){}",
+                ANSI_GREEN,
+                ANSI_RESET,
+                summary.passed,
+                ANSI_ITALIC,
+                started.elapsed(),
+                ANSI_RESET
+            );
+            true
+        }
+        Some(summary) => {
+            // show the assertion messages of the failed tests
+            if let Some(failures) = stdout.split("\nfailures:\n").nth(1) {
+                println!("{}", failures.trim_end());
+            }
+            println!(
+                "{}tests FAILED{}: {} passed, {} failed ({})",
+                ANSI_RED,
+                ANSI_RESET,
+                summary.passed,
+                summary.failed.len(),
+                summary.failed.join(", ")
+            );
+            true
+        }
+        None => {
+            // the compiler's errors are on stderr
+            print!("{}", String::from_utf8_lossy(&output.stderr));
+            println!("{}build FAILED{}", ANSI_RED, ANSI_RESET);
+            false
+        }
+    }
+}
+
+fn run_solution(bin_name: &str, release: bool) {
+    let mut args = vec!["run", "--quiet", "--bin", bin_name];
+    if release {
+        args.push("--release");
+    }
+    args.extend(["--", "--json"]);
+
+    let output = match cargo(&args) {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run `cargo run`: {}", e);
+            return;
+        }
+    };
+
+    let results = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| serde_json::from_str::<PartResult>(line).ok())
+        .collect::<Vec<_>>();
+    if results.is_empty() {
+        print!("{}", String::from_utf8_lossy(&output.stderr));
+    }
+
+    for result in results {
+        let outcome = match (result.status, &result.answer, &result.error) {
+            (Status::Solved, Some(answer), _) => format!(
+                "{} {}({:.2?}){}",
+                answer,
+                ANSI_ITALIC,
+                result.elapsed(),
+                ANSI_RESET
+            ),
+            (Status::Failed, _, Some(error)) => {
+                format!("{}error:{} {}", ANSI_RED, ANSI_RESET, error)
+            }
+            _ => "not solved.".to_string(),
+        };
+        println!("part {}: {}", result.part, outcome);
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!("example: `cargo watch-day 7 --release`");
+            process::exit(1);
+        }
+    };
+
+    let bin_name = advent_of_code::bin_name(args.year, args.day);
+    let module_path = PathBuf::from(format!("src/bin/{}.rs", bin_name));
+    if !module_path.exists() {
+        eprintln!(
+            "\"{}\" does not exist, scaffold it first.",
+            module_path.display()
+        );
+        process::exit(1);
+    }
+
+    let mut watcher = Watcher::new(vec![
+        module_path,
+        PathBuf::from("src/helpers.rs"),
+        PathBuf::from(format!("src/examples/{}/{:02}.txt", args.year, args.day)),
+        advent_of_code::inputs_dir(args.year).join(format!("{:02}.txt", args.day)),
+    ]);
+
+    println!("Watching for changes (Ctrl+C to stop):");
+    for path in watcher.paths() {
+        println!("  {}", path.display());
+    }
+
+    let interval = Duration::from_millis(args.interval);
+    let mut changed = Vec::new();
+    loop {
+        println!("----------");
+        match changed.as_slice() {
+            [] => println!("{}| {} |{}", ANSI_BOLD, bin_name, ANSI_RESET),
+            changed => println!(
+                "{}| {} |{} changed: {}",
+                ANSI_BOLD,
+                bin_name,
+                ANSI_RESET,
+                changed
+                    .iter()
+                    .map(|p: &PathBuf| p.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+        println!("----------");
+
+        // a part can be worth running before the other one passes its example
+        if run_tests(&bin_name) {
+            run_solution(&bin_name, args.release);
+        }
+
+        changed = loop {
+            thread::sleep(interval);
+            let changed = watcher.poll();
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+    }
+}
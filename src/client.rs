@@ -0,0 +1,292 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::{env, fs, io, process};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const CACHE_DIR: &str = "target/aoc-cache";
+
+/// Holds the session cookie itself.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Points to a file holding the session cookie.
+pub const SESSION_FILE_ENV: &str = "AOC_SESSION_FILE";
+/// Same file as used by aoc-cli, relative to the home directory.
+pub const SESSION_FILE: &str = ".adventofcode.session";
+
+#[derive(Debug)]
+pub enum Error {
+    MissingSession,
+    /// The server answered with an error status, e.g. 404 for a puzzle that isn't unlocked yet.
+    Status {
+        url: String,
+        status: u16,
+    },
+    /// The server could not be reached.
+    Transport(String),
+    Io(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::MissingSession => write!(
+                f,
+                "no session cookie found, set {} or store it in \"~/{}\"",
+                SESSION_ENV, SESSION_FILE
+            ),
+            Error::Status { url, status } => write!(f, "{} returned status {}", url, status),
+            Error::Transport(e) => write!(f, "{}", e),
+            Error::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+/// Read the session cookie from `AOC_SESSION`, the file named by `AOC_SESSION_FILE`,
+/// or `~/.adventofcode.session`, in that order.
+pub fn find_session() -> Result<String, Error> {
+    if let Ok(session) = env::var(SESSION_ENV) {
+        return Ok(session.trim().to_string());
+    }
+
+    let path = match env::var_os(SESSION_FILE_ENV) {
+        Some(path) => PathBuf::from(path),
+        None => PathBuf::from(env::var_os("HOME").ok_or(Error::MissingSession)?).join(SESSION_FILE),
+    };
+
+    match fs::read_to_string(path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        _ => Err(Error::MissingSession),
+    }
+}
+
+/// Fetches puzzles and inputs from adventofcode.com, or any server with the same URL layout.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    cache_dir: Option<PathBuf>,
+    refresh: bool,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            agent: ureq::AgentBuilder::new()
+                .user_agent("github.com/JakuJ/advent-of-code-2022 download")
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            cache_dir: None,
+            refresh: false,
+        }
+    }
+
+    /// Store every response in `dir` and answer later requests from there.
+    pub fn with_cache(mut self, dir: impl AsRef<Path>) -> Self {
+        self.cache_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Always fetch, but still update the cache. The puzzle description changes once part one is solved.
+    pub fn refresh(mut self, refresh: bool) -> Self {
+        self.refresh = refresh;
+        self
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, Error> {
+        self.get(&format!("{}/day/{}/input", year, day))
+    }
+
+    /// The puzzle description as HTML.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, Error> {
+        self.get(&format!("{}/day/{}", year, day))
+    }
+
+    /// Cached responses are kept apart per server and per account, e.g.
+    /// `adventofcode.com/1f2e3d4c/2022/day/1/input/response`.
+    fn cache_path(&self, dir: &Path, path: &str) -> PathBuf {
+        let host = self
+            .base_url
+            .split_once("://")
+            .map_or(self.base_url.as_str(), |(_, rest)| rest)
+            .split('/')
+            .next()
+            .unwrap_or_default()
+            .replace(':', "_");
+        dir.join(host)
+            .join(session_hash(&self.session))
+            .join(path)
+            .join("response")
+    }
+
+    fn get(&self, path: &str) -> Result<String, Error> {
+        let cached = self
+            .cache_dir
+            .as_ref()
+            .map(|dir| self.cache_path(dir, path));
+
+        if let Some(cached) = cached.as_ref().filter(|_| !self.refresh) {
+            if let Ok(body) = fs::read_to_string(cached) {
+                return Ok(body);
+            }
+        }
+
+        let url = format!("{}/{}", self.base_url, path);
+        let body = match self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+        {
+            Ok(response) => response.into_string()?,
+            Err(ureq::Error::Status(status, _)) => return Err(Error::Status { url, status }),
+            Err(ureq::Error::Transport(e)) => return Err(Error::Transport(e.to_string())),
+        };
+
+        if let Some(cached) = cached {
+            write_atomically(&cached, &body)?;
+        }
+        Ok(body)
+    }
+}
+
+/// A short FNV-1a hash of the session, so the cache tells accounts apart without storing the cookie.
+fn session_hash(session: &str) -> String {
+    let hash = session
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+    format!("{:08x}", hash >> 32)
+}
+
+/// Write to a file unique to this process first, so concurrent downloads never see half a file.
+pub fn write_atomically(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension(format!("tmp{}", process::id()));
+    fs::write(&tmp, contents)?;
+    fs::rename(tmp, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serve `responses` to consecutive connections, returning the request lines and cookies.
+    fn mock_server(
+        responses: Vec<(u16, &'static str)>,
+    ) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if line.starts_with("GET") || line.to_lowercase().starts_with("cookie") {
+                        request.push_str(line.trim());
+                        request.push(' ');
+                    }
+                }
+                requests.push(request.trim().to_string());
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_download_and_cache() {
+        let (base_url, server) = mock_server(vec![(200, "1\n2\n3\n"), (200, "<main></main>")]);
+        let cache = env::temp_dir().join(format!("aoc-cache-test-{}", process::id()));
+        let client = Client::new(&(base_url + "/"), "abc").with_cache(&cache);
+
+        assert_eq!(client.input(2022, 1).unwrap(), "1\n2\n3\n");
+        // answered from the cache, the server only accepts two connections
+        assert_eq!(client.input(2022, 1).unwrap(), "1\n2\n3\n");
+        assert_eq!(client.puzzle(2022, 1).unwrap(), "<main></main>");
+
+        assert_eq!(
+            server.join().unwrap(),
+            [
+                "GET /2022/day/1/input HTTP/1.1 Cookie: session=abc",
+                "GET /2022/day/1 HTTP/1.1 Cookie: session=abc"
+            ]
+        );
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn test_cache_per_session_and_server() {
+        let (first_url, first) = mock_server(vec![(200, "alice"), (200, "bob")]);
+        let (second_url, second) = mock_server(vec![(200, "mock")]);
+        let cache = env::temp_dir().join(format!("aoc-cache-accounts-test-{}", process::id()));
+        let client = |base_url: &str, session| Client::new(base_url, session).with_cache(&cache);
+
+        assert_eq!(client(&first_url, "alice").input(2022, 1).unwrap(), "alice");
+        assert_eq!(client(&first_url, "bob").input(2022, 1).unwrap(), "bob");
+        assert_eq!(client(&second_url, "alice").input(2022, 1).unwrap(), "mock");
+        // all cached by now, the servers don't accept any more connections
+        assert_eq!(client(&first_url, "alice").input(2022, 1).unwrap(), "alice");
+        assert_eq!(client(&first_url, "bob").input(2022, 1).unwrap(), "bob");
+        assert_eq!(client(&second_url, "alice").input(2022, 1).unwrap(), "mock");
+
+        first.join().unwrap();
+        second.join().unwrap();
+        assert_ne!(session_hash("alice"), session_hash("bob"));
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn test_errors() {
+        let (base_url, server) = mock_server(vec![(404, "Not found")]);
+        let client = Client::new(&base_url, "abc");
+
+        match client.input(2022, 25) {
+            Err(Error::Status { url, status }) => {
+                assert_eq!(url, format!("{}/2022/day/25/input", base_url));
+                assert_eq!(status, 404);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        server.join().unwrap();
+
+        // nothing listens there anymore
+        assert!(matches!(client.input(2022, 25), Err(Error::Transport(_))));
+    }
+}
@@ -0,0 +1,92 @@
+/*
+ * Registry of all days, compiled into `cargo all` and other in-process runners.
+ * `cargo scaffold` adds new days to both lists below, and `cargo test --test answers`
+ * fails for any day in `src/bin` that is missing here.
+ * A day can get its own runner limits, e.g.
+ * `Day::new(2022, 20, ...).with_limits(Limits::default().timeout(Duration::from_secs(5)))`.
+ */
+// each day is also a binary with its own `main` and crate-level attributes
+#![allow(dead_code, unused_attributes)]
+
+use advent_of_code::runner::{Day, Solution};
+
+#[path = "bin/2022-01.rs"]
+mod y2022_d01;
+#[path = "bin/2022-02.rs"]
+mod y2022_d02;
+#[path = "bin/2022-03.rs"]
+mod y2022_d03;
+#[path = "bin/2022-04.rs"]
+mod y2022_d04;
+#[path = "bin/2022-05.rs"]
+mod y2022_d05;
+#[path = "bin/2022-06.rs"]
+mod y2022_d06;
+#[path = "bin/2022-07.rs"]
+mod y2022_d07;
+#[path = "bin/2022-08.rs"]
+mod y2022_d08;
+#[path = "bin/2022-09.rs"]
+mod y2022_d09;
+#[path = "bin/2022-10.rs"]
+mod y2022_d10;
+#[path = "bin/2022-11.rs"]
+mod y2022_d11;
+#[path = "bin/2022-12.rs"]
+mod y2022_d12;
+#[path = "bin/2022-13.rs"]
+mod y2022_d13;
+#[path = "bin/2022-14.rs"]
+mod y2022_d14;
+#[path = "bin/2022-15.rs"]
+mod y2022_d15;
+#[path = "bin/2022-16.rs"]
+mod y2022_d16;
+#[path = "bin/2022-17.rs"]
+mod y2022_d17;
+#[path = "bin/2022-18.rs"]
+mod y2022_d18;
+#[path = "bin/2022-19.rs"]
+mod y2022_d19;
+#[path = "bin/2022-20.rs"]
+mod y2022_d20;
+#[path = "bin/2022-21.rs"]
+mod y2022_d21;
+#[path = "bin/2022-22.rs"]
+mod y2022_d22;
+#[path = "bin/2022-23.rs"]
+mod y2022_d23;
+#[path = "bin/2022-24.rs"]
+mod y2022_d24;
+#[path = "bin/2022-25.rs"]
+mod y2022_d25;
+
+pub fn all() -> Vec<Box<dyn Solution>> {
+    vec![
+        Box::new(Day::new(2022, 1, y2022_d01::part_one, y2022_d01::part_two)),
+        Box::new(Day::new(2022, 2, y2022_d02::part_one, y2022_d02::part_two)),
+        Box::new(Day::new(2022, 3, y2022_d03::part_one, y2022_d03::part_two)),
+        Box::new(Day::new(2022, 4, y2022_d04::part_one, y2022_d04::part_two)),
+        Box::new(Day::new(2022, 5, y2022_d05::part_one, y2022_d05::part_two)),
+        Box::new(Day::new(2022, 6, y2022_d06::part_one, y2022_d06::part_two)),
+        Box::new(Day::new(2022, 7, y2022_d07::part_one, y2022_d07::part_two)),
+        Box::new(Day::new(2022, 8, y2022_d08::part_one, y2022_d08::part_two)),
+        Box::new(Day::new(2022, 9, y2022_d09::part_one, y2022_d09::part_two)),
+        Box::new(Day::new(2022, 10, y2022_d10::part_one, y2022_d10::part_two)),
+        Box::new(Day::new(2022, 11, y2022_d11::part_one, y2022_d11::part_two)),
+        Box::new(Day::new(2022, 12, y2022_d12::part_one, y2022_d12::part_two)),
+        Box::new(Day::new(2022, 13, y2022_d13::part_one, y2022_d13::part_two)),
+        Box::new(Day::new(2022, 14, y2022_d14::part_one, y2022_d14::part_two)),
+        Box::new(Day::new(2022, 15, y2022_d15::part_one, y2022_d15::part_two)),
+        Box::new(Day::new(2022, 16, y2022_d16::part_one, y2022_d16::part_two)),
+        Box::new(Day::new(2022, 17, y2022_d17::part_one, y2022_d17::part_two)),
+        Box::new(Day::new(2022, 18, y2022_d18::part_one, y2022_d18::part_two)),
+        Box::new(Day::new(2022, 19, y2022_d19::part_one, y2022_d19::part_two)),
+        Box::new(Day::new(2022, 20, y2022_d20::part_one, y2022_d20::part_two)),
+        Box::new(Day::new(2022, 21, y2022_d21::part_one, y2022_d21::part_two)),
+        Box::new(Day::new(2022, 22, y2022_d22::part_one, y2022_d22::part_two)),
+        Box::new(Day::new(2022, 23, y2022_d23::part_one, y2022_d23::part_two)),
+        Box::new(Day::new(2022, 24, y2022_d24::part_one, y2022_d24::part_two)),
+        Box::new(Day::new(2022, 25, y2022_d25::part_one, y2022_d25::part_two)),
+    ]
+}
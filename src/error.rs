@@ -0,0 +1,116 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::alloc_stats::Bytes;
+use std::any::Any;
+use std::fmt::{self, Display};
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Everything that can go wrong when running a part, short of a wrong answer.
+#[derive(Debug)]
+pub enum Error {
+    /// The input file could not be read.
+    MissingInput { path: PathBuf, source: io::Error },
+    /// The input is malformed. Lines and columns start at 1.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The part has no solution yet.
+    Unsolved,
+    /// The solver panicked.
+    Panic(String),
+    /// The part ran longer than its timeout and was stopped.
+    Timeout(Duration),
+    /// The part used more memory than allowed (in bytes) and was stopped.
+    OutOfMemory { limit: u64 },
+    /// The process running the part died, e.g. of a stack overflow.
+    Crashed(String),
+}
+
+impl Error {
+    pub fn parse(line: usize, column: usize, message: impl Display) -> Self {
+        Error::Parse {
+            line,
+            column,
+            message: message.to_string(),
+        }
+    }
+
+    /// A parse error at byte `offset` of `input`, for parsers that don't go line by line.
+    pub fn parse_at(input: &str, offset: usize, message: impl Display) -> Self {
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rsplit('\n')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .count()
+            + 1;
+        Error::parse(line, column, message)
+    }
+
+    /// Moves a parse error to line `line`, for errors from parsing a single line of the input.
+    /// Other errors are returned as they are.
+    pub fn on_line(self, line: usize) -> Self {
+        match self {
+            Error::Parse {
+                line: offset,
+                column,
+                message,
+            } => Error::Parse {
+                line: line + offset - 1,
+                column,
+                message,
+            },
+            other => other,
+        }
+    }
+
+    pub(crate) fn from_panic(payload: Box<dyn Any + Send>) -> Self {
+        let message = if let Some(s) = payload.downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = payload.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "unknown panic payload".to_string()
+        };
+        Error::Panic(message)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::MissingInput { path, source } => {
+                write!(f, "could not read \"{}\": {}", path.display(), source)
+            }
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "parse error at {}:{}: {}", line, column, message),
+            Error::Unsolved => write!(f, "not solved."),
+            Error::Panic(message) => write!(f, "solver panicked: {}", message),
+            Error::Timeout(timeout) => write!(f, "TIMEOUT after {:.2?}", timeout),
+            Error::OutOfMemory { limit } => {
+                write!(f, "OOM, used more than {}", Bytes(*limit))
+            }
+            Error::Crashed(message) => write!(f, "solver crashed: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::MissingInput { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -3,31 +3,59 @@
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
 
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
 use std::str::FromStr;
 
 use regex::Regex;
 
-pub fn parse_with_regex<T, const N: usize>(re: &Regex, line: &str) -> [T; N]
+use crate::Error;
+
+/// The `N` capture groups of `re` in `line`, parsed as `T`. Errors point into `line`, so they
+/// are on line 1. [`parse_lines`] moves them to the line of the input they occurred on.
+pub fn parse_with_regex<T, const N: usize>(re: &Regex, line: &str) -> Result<[T; N], Error>
 where
     T: Default,
     T: Copy,
     T: FromStr,
 {
     let mut caps = re.capture_locations();
-    re.captures_read(&mut caps, line);
+    if re.captures_read(&mut caps, line).is_none() {
+        return Err(Error::parse(
+            1,
+            1,
+            format!("{:?} does not match {:?}", line, re.as_str()),
+        ));
+    }
 
     let mut arr = [Default::default(); N];
 
     for i in 1..=N {
-        unsafe {
-            let (i0, i1) = caps.get(i).unwrap_unchecked();
-            let val = &line[i0..i1];
+        let (i0, i1) = caps.get(i).unwrap_or((line.len(), line.len()));
+        let val = &line[i0..i1];
 
-            arr[i - 1] = val.parse().unwrap_unchecked()
-        }
+        arr[i - 1] = val.parse().map_err(|_| {
+            Error::parse(
+                1,
+                line[..i0].chars().count() + 1,
+                format!("{:?} is not a valid {}", val, std::any::type_name::<T>()),
+            )
+        })?;
     }
 
-    arr
+    Ok(arr)
+}
+
+/// Parse every line of `input` with `f`, which reports errors as if its line was the first one.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut f: impl FnMut(&'a str) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| e.on_line(i + 1)))
+        .collect()
 }
 
 /// # Safety
@@ -54,3 +82,412 @@ pub fn disjoint_mut_refs_3<T>(
         )
     }
 }
+
+/// Offsets of the orthogonal neighbours of a cell, clockwise starting upwards.
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+/// Offsets of the orthogonal and diagonal neighbours of a cell, row by row.
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid, stored row by row in a single `Vec`.
+/// Positions are `(x, y)` with `(0, 0)` in the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// # Panics
+    /// If there aren't exactly `width * height` cells.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Grid::new(width, height, cells)
+    }
+
+    /// One row per line and one cell per character, e.g. `Grid::parse(input, |c| c == '#')`.
+    /// All lines need to be equally long.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Result<Self, Error> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let start = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            let length = cells.len() - start;
+
+            match width {
+                None => width = Some(length),
+                Some(width) if width != length => {
+                    return Err(Error::parse(
+                        y + 1,
+                        length.min(width) + 1,
+                        format!("expected a row of {} cells, got {}", width, length),
+                    ))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+
+    /// Like [`Grid::parse`], but lines shorter than the longest one are padded with `fill`.
+    pub fn parse_ragged(input: &str, fill: T, mut f: impl FnMut(char) -> T) -> Self
+    where
+        T: Clone,
+    {
+        let width = input
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let mut cells = Vec::with_capacity(input.len());
+        for line in input.lines() {
+            let end = cells.len() + width;
+            cells.extend(line.chars().map(&mut f));
+            cells.resize(end, fill.clone());
+        }
+
+        let height = cells.len() / width.max(1);
+        Grid::new(width, height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// All cells, row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    /// Index of `(x, y)` in [`Grid::cells`].
+    #[inline]
+    pub fn index(&self, x: usize, y: usize) -> usize {
+        x + y * self.width
+    }
+
+    /// Position of the cell at `index` in [`Grid::cells`].
+    #[inline]
+    pub fn position(&self, index: usize) -> (usize, usize) {
+        (index % self.width, index / self.width)
+    }
+
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[self.index(x, y)])
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            let index = self.index(x, y);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    /// # Safety
+    /// `x` has to be less than the width and `y` less than the height.
+    #[inline]
+    pub unsafe fn get_unchecked(&self, x: usize, y: usize) -> &T {
+        unsafe { self.cells.get_unchecked(x + y * self.width) }
+    }
+
+    /// # Safety
+    /// `x` has to be less than the width and `y` less than the height.
+    #[inline]
+    pub unsafe fn get_unchecked_mut(&mut self, x: usize, y: usize) -> &mut T {
+        unsafe { self.cells.get_unchecked_mut(x + y * self.width) }
+    }
+
+    /// The position `(dx, dy)` away from `(x, y)`, if it is on the grid.
+    #[inline]
+    pub fn offset(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+        Some((x, y))
+    }
+
+    /// Positions of the up to 4 orthogonal neighbours of `(x, y)`.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS_4
+            .iter()
+            .filter_map(move |&delta| self.offset((x, y), delta))
+    }
+
+    /// Positions of the up to 8 orthogonal and diagonal neighbours of `(x, y)`.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS_8
+            .iter()
+            .filter_map(move |&delta| self.offset((x, y), delta))
+    }
+
+    /// Every position with its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.position(index), cell))
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // `chunks` doesn't take 0, an empty grid has no rows either way
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// The cells of column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {} is outside of the grid", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// The cells from `(x, y)` (excluded) up to the edge of the grid, going `(dx, dy)` at a time.
+    /// This is what can be seen from `(x, y)` when looking in that direction.
+    pub fn ray(&self, x: usize, y: usize, delta: (isize, isize)) -> impl Iterator<Item = &T> {
+        assert_ne!(delta, (0, 0), "a ray needs a direction");
+        std::iter::successors(self.offset((x, y), delta), move |&p| self.offset(p, delta))
+            .map(|(x, y)| &self[(x, y)])
+    }
+
+    /// The cells from `(x, y)` (included) towards the bottom right corner.
+    pub fn diagonal(&self, x: usize, y: usize) -> impl Iterator<Item = &T> {
+        std::iter::once(&self[(x, y)]).chain(self.ray(x, y, (1, 1)))
+    }
+
+    /// The cells from `(x, y)` (included) towards the bottom left corner.
+    pub fn anti_diagonal(&self, x: usize, y: usize) -> impl Iterator<Item = &T> {
+        std::iter::once(&self[(x, y)]).chain(self.ray(x, y, (-1, 1)))
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(
+            self.width,
+            self.height,
+            self.cells.iter().map(&mut f).collect(),
+        )
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Rows become columns and columns become rows.
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    /// Turned by 90° clockwise.
+    pub fn rotate_right(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |x, y| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// Turned by 90° counterclockwise.
+    pub fn rotate_left(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |x, y| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+
+    /// Surrounded by a border `n` cells wide, e.g. so that neighbours never fall off the grid.
+    pub fn pad(&self, n: usize, fill: T) -> Self {
+        Grid::from_fn(self.width + 2 * n, self.height + 2 * n, |x, y| {
+            match (x.checked_sub(n), y.checked_sub(n)) {
+                (Some(x), Some(y)) => self.get(x, y).unwrap_or(&fill).clone(),
+                _ => fill.clone(),
+            }
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        match self.get(x, y) {
+            Some(cell) => cell,
+            None => panic!(
+                "({}, {}) is outside of the {}x{} grid",
+                x, y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    #[inline]
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(x, y) {
+            Some(cell) => cell,
+            None => panic!("({}, {}) is outside of the {}x{} grid", x, y, width, height),
+        }
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Grid::parse(s, |c| c)
+    }
+}
+
+/// Prints the rows on separate lines, without separating the cells.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_with_regex() {
+        let re = Regex::new(r"^(\d+)-(\d+)$").unwrap();
+        assert_eq!(parse_with_regex::<u8, 2>(&re, "3-14").unwrap(), [3, 14]);
+        assert_eq!(
+            parse_with_regex::<u8, 2>(&re, "3-")
+                .unwrap_err()
+                .to_string(),
+            r#"parse error at 1:1: "3-" does not match "^(\\d+)-(\\d+)$""#
+        );
+
+        let lines = parse_lines("1-2\n3-400", |line| parse_with_regex::<u8, 2>(&re, line));
+        assert_eq!(
+            lines.unwrap_err().to_string(),
+            r#"parse error at 2:3: "400" is not a valid u8"#
+        );
+    }
+
+    #[test]
+    fn test_grid_access() {
+        let mut grid: Grid<char> = "abc\ndef\n".parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(unsafe { *grid.get_unchecked(1, 1) }, 'e');
+        assert_eq!(grid.position(grid.index(1, 1)), (1, 1));
+
+        grid[(0, 0)] = 'A';
+        *grid.get_mut(1, 0).unwrap() = 'B';
+        assert_eq!(grid.row(0), ['A', 'B', 'c']);
+        assert_eq!(grid.column(2).rev().collect::<String>(), "fc");
+        assert_eq!(grid.to_string(), "ABc\ndef");
+
+        let err = Grid::parse("ab\nabc", |c| c).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at 2:3: expected a row of 2 cells, got 3"
+        );
+        let ragged = Grid::parse_ragged("ab\nabcd\n", '.', |c| c);
+        assert_eq!(ragged.to_string(), "ab..\nabcd");
+    }
+
+    #[test]
+    fn test_grid_neighbours_and_views() {
+        let grid = Grid::from_fn(4, 3, |x, y| x + 10 * y);
+
+        assert_eq!(grid.neighbours4(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours4(1, 1).count(), 4);
+        assert_eq!(grid.neighbours8(3, 2).count(), 3);
+        assert_eq!(grid.neighbours8(1, 1).count(), 8);
+
+        assert_eq!(grid.ray(1, 1, (1, 0)).collect::<Vec<_>>(), [&12, &13]);
+        assert_eq!(grid.ray(1, 1, (0, -1)).collect::<Vec<_>>(), [&1]);
+        assert_eq!(grid.diagonal(1, 0).collect::<Vec<_>>(), [&1, &12, &23]);
+        assert_eq!(grid.anti_diagonal(1, 0).collect::<Vec<_>>(), [&1, &10]);
+        assert_eq!(grid.rows().count(), 3);
+        assert_eq!(
+            grid.iter().find(|&(_, &cell)| cell == 21).map(|(p, _)| p),
+            Some((1, 2))
+        );
+    }
+
+    #[test]
+    fn test_grid_transforms() {
+        let grid: Grid<char> = "ab\ncd\nef".parse().unwrap();
+        assert_eq!(grid.transpose().to_string(), "ace\nbdf");
+        assert_eq!(grid.rotate_right().to_string(), "eca\nfdb");
+        assert_eq!(grid.rotate_left().to_string(), "bdf\nace");
+        assert_eq!(grid.rotate_left().rotate_right(), grid);
+        assert_eq!(grid.pad(1, '.').to_string(), "....\n.ab.\n.cd.\n.ef.\n....");
+        assert_eq!(
+            grid.map(|&c| c == 'a')
+                .cells()
+                .iter()
+                .filter(|&&a| a)
+                .count(),
+            1
+        );
+        assert_eq!(Grid::filled(2, 1, 0).to_string(), "00");
+    }
+}
@@ -3,92 +3,402 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
+use alloc_stats::AllocStats;
+use answers::Verdict;
+use serde::{Deserialize, Serialize};
 use std::env;
+use std::fmt::Display;
 use std::fs;
+use std::io::{self, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::Once;
+use std::time::{Duration, Instant};
 
+pub use error::Error;
+
+pub mod alloc_stats;
+pub mod answers;
+pub mod baseline;
+pub mod client;
+mod error;
 pub mod helpers;
+pub mod limits;
+pub mod puzzle;
+pub mod readme;
+pub mod runner;
+pub mod template;
+pub mod watch;
+
+/// Year used when neither `--year` nor `AOC_YEAR` is given.
+pub const DEFAULT_YEAR: u16 = 2022;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
 
-#[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
+/// Outcome of running a single part of a day, as emitted in `json` output mode.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub elapsed_ns: u64,
+    /// CPU time the part's thread spent, if the platform tells. Unlike `elapsed_ns`,
+    /// this leaves out the time spent waiting for a CPU while other days run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_ns: Option<u64>,
+    /// Heap usage of the part, if built with the `alloc-stats` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alloc: Option<AllocStats>,
+    pub status: Status,
+    /// Why the part failed, if it did.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// How the answer compares to the known answers in `answers.toml`, if it was checked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<Verdict>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Solved,
+    Unsolved,
+    Failed,
+}
+
+impl PartResult {
+    pub fn new(
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: Result<String, Error>,
+        elapsed: Duration,
+    ) -> Self {
+        let (answer, status, error) = match answer {
+            Ok(answer) => (Some(answer), Status::Solved, None),
+            Err(Error::Unsolved) => (None, Status::Unsolved, None),
+            Err(e) => (None, Status::Failed, Some(e.to_string())),
+        };
+
+        PartResult {
+            year,
+            day,
+            part,
+            answer,
+            elapsed_ns: elapsed.as_nanos() as u64,
+            cpu_ns: None,
+            alloc: None,
+            status,
+            error,
+            verdict: None,
+        }
+    }
+
+    /// A part that could not be run at all, e.g. because its input is missing.
+    pub fn failed(year: u16, day: u8, part: u8, error: &Error) -> Self {
+        PartResult {
+            year,
+            day,
+            part,
+            answer: None,
+            elapsed_ns: 0,
+            cpu_ns: None,
+            alloc: None,
+            status: Status::Failed,
+            error: Some(error.to_string()),
+            verdict: None,
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_ns)
+    }
+
+    /// CPU time of the part, or its elapsed time where that is not measured.
+    pub fn cpu_time(&self) -> Duration {
+        Duration::from_nanos(self.cpu_ns.unwrap_or(self.elapsed_ns))
+    }
+}
+
+/// Return types accepted from `part_one` and `part_two`: `None` and `Err(Error::Unsolved)`
+/// both mean that the part is not solved yet.
+pub trait IntoAnswer {
+    type Answer: Display;
+    fn into_answer(self) -> Result<Self::Answer, Error>;
+}
+
+impl<T: Display> IntoAnswer for Option<T> {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<T, Error> {
+        self.ok_or(Error::Unsolved)
+    }
+}
+
+impl<T: Display> IntoAnswer for Result<T, Error> {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<T, Error> {
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputMode {
+    /// Decorated output for humans, e.g. `42 (elapsed: 1.20ms)`.
+    Human,
+    /// One JSON record per line, see [`PartResult`].
+    Json,
+}
+
+/// `json` output is selected with `AOC_OUTPUT=json` or the `--json` flag.
+pub fn output_mode() -> OutputMode {
+    let from_env = env::var("AOC_OUTPUT").is_ok_and(|v| v == "json");
+    if from_env || env::args().any(|arg| arg == "--json") {
+        OutputMode::Json
+    } else {
+        OutputMode::Human
+    }
+}
+
+/// Run `func` on `input`, timing only the solution itself (and counting its allocations
+/// with the `alloc-stats` feature). A panicking solver is reported as a failed part.
+pub fn run_part<R: IntoAnswer>(
+    year: u16,
+    day: u8,
+    part: u8,
+    func: impl FnOnce(&str) -> R,
+    input: &str,
+) -> PartResult {
+    let ((result, elapsed, cpu), alloc) = alloc_stats::measure(|| {
+        let cpu_start = thread_cpu_time();
+        let timer = Instant::now();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| func(input)));
+        let elapsed = timer.elapsed();
+        let cpu = cpu_start
+            .zip(thread_cpu_time())
+            .map(|(start, end)| end - start);
+        (result, elapsed, cpu)
+    });
+
+    let answer = match result {
+        Ok(result) => result.into_answer().map(|answer| answer.to_string()),
+        Err(payload) => Err(Error::from_panic(payload)),
+    };
+
+    PartResult {
+        alloc,
+        cpu_ns: cpu.map(|cpu| cpu.as_nanos() as u64),
+        ..PartResult::new(year, day, part, answer, elapsed)
+    }
+}
+
+/// CPU time used by the current thread so far, if the platform tells.
+#[cfg(unix)]
+fn thread_cpu_time() -> Option<Duration> {
+    let mut time = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    // SAFETY: `time` is a valid timespec for the call to write to
+    let status = unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut time) };
+    (status == 0).then(|| Duration::new(time.tv_sec as u64, time.tv_nsec as u32))
+}
+
+#[cfg(not(unix))]
+fn thread_cpu_time() -> Option<Duration> {
+    None
+}
+
+pub fn print_result(result: &PartResult, mode: OutputMode) {
+    match mode {
+        OutputMode::Human => {
+            println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, result.part, ANSI_RESET);
+            match (&result.answer, &result.error) {
+                (Some(answer), _) => {
                     println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
+                        "{} {}(elapsed: {:.2?}{}){}{}",
+                        answer,
+                        ANSI_ITALIC,
+                        result.elapsed(),
+                        result
+                            .alloc
+                            .map(|alloc| format!(", {}", alloc))
+                            .unwrap_or_default(),
+                        ANSI_RESET,
+                        result
+                            .verdict
+                            .map(|v| format!(" {}", v))
+                            .unwrap_or_default()
                     );
                 }
-                None => {
+                (None, Some(error)) => {
+                    println!("{}error: {}{}", ANSI_RED, error, ANSI_RESET)
+                }
+                (None, None) => {
                     println!("not solved.")
                 }
             }
         }
+        OutputMode::Json => {
+            println!("{}", serde_json::to_string(result).unwrap());
+        }
+    }
+}
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+/// Run and print one part. `$input` is the result of [`read_input`],
+/// so a missing input is reported as a failed part.
+#[macro_export]
+macro_rules! solve {
+    ($part:expr, $solver:ident, $input:expr) => {{
+        // binaries are named `<year>-<day>`
+        let (year, day) = option_env!("CARGO_BIN_NAME")
+            .and_then(|name| {
+                let (year, day) = name.split_once('-')?;
+                Some((year.parse().ok()?, day.parse().ok()?))
+            })
+            .unwrap_or((advent_of_code::default_year(), 0));
+        let result = match $input {
+            Ok(input) => advent_of_code::run_part(year, day, $part, $solver, input),
+            Err(e) => advent_of_code::PartResult::failed(year, day, $part, e),
+        };
+        advent_of_code::print_result(&result, advent_of_code::output_mode());
     }};
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
+/// The year given by `AOC_YEAR`, or [`DEFAULT_YEAR`]. Commands that take `--year` fall back to this.
+pub fn default_year() -> u16 {
+    env::var("AOC_YEAR")
+        .ok()
+        .and_then(|year| year.parse().ok())
+        .unwrap_or(DEFAULT_YEAR)
+}
+
+/// Name of the binary solving `day` of `year`, e.g. `2022-01`.
+pub fn bin_name(year: u16, day: u8) -> String {
+    format!("{}-{:02}", year, day)
+}
+
+/// `src/<folder>/<year>`, e.g. `src/inputs/2022`.
+pub fn year_dir(folder: &str, year: u16) -> PathBuf {
     let cwd = env::current_dir().unwrap();
+    cwd.join("src").join(folder).join(year.to_string())
+}
+
+pub fn input_path(folder: &str, year: u16, day: u8) -> PathBuf {
+    year_dir(folder, year).join(format!("{:02}.txt", day))
+}
+
+/// Directory with the inputs of `year`, see [`with_flat_fallback`].
+pub fn inputs_dir(year: u16) -> PathBuf {
+    with_flat_fallback(year_dir("inputs", year), year)
+}
+
+/// Checkouts from before inputs were kept per year have the [`DEFAULT_YEAR`] inputs directly
+/// in `src/inputs`. Until they are moved, those are read instead of the missing `dir`.
+fn with_flat_fallback(dir: PathBuf, year: u16) -> PathBuf {
+    let flat = dir.parent().unwrap();
+    let is_day_file = |name: &str| {
+        name.len() == 6 && name.ends_with(".txt") && name[..2].bytes().all(|b| b.is_ascii_digit())
+    };
+    let has_days = || {
+        fs::read_dir(flat)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .any(|entry| entry.file_name().to_str().is_some_and(is_day_file))
+    };
+    if year != DEFAULT_YEAR || dir.exists() || !has_days() {
+        return dir;
+    }
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
-
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
-}
-
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
-}
-
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
+    static HINT: Once = Once::new();
+    HINT.call_once(|| {
+        let cwd = env::current_dir().unwrap_or_default();
+        let relative = |path: &Path| path.strip_prefix(&cwd).unwrap_or(path).display().to_string();
+        eprintln!(
+            "warning: reading inputs from {flat}, they belong in {dir} now. To move them: mkdir -p {dir} && mv {flat}/*.txt {dir}/",
+            flat = relative(flat),
+            dir = relative(&dir),
+        )
+    });
+    flat.to_path_buf()
+}
+
+pub fn read_file(folder: &str, year: u16, day: u8) -> Result<String, Error> {
+    match folder {
+        "inputs" => read_path(inputs_dir(year).join(format!("{:02}.txt", day))),
+        _ => read_path(input_path(folder, year, day)),
+    }
+}
+
+fn read_path(path: PathBuf) -> Result<String, Error> {
+    fs::read_to_string(&path).map_err(|source| Error::MissingInput { path, source })
+}
+
+/// Where the puzzle input comes from when running a solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    /// A directory with one `NN.txt` file per day.
+    Dir(PathBuf),
+    /// A single file, used regardless of the day.
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// `--input <path>` (a file, a directory or `-` for stdin) takes precedence over
+    /// the `AOC_INPUT_DIR` environment variable, which takes precedence over `src/inputs/<year>`.
+    pub fn from_env(year: u16) -> Self {
+        let args = env::args().collect::<Vec<_>>();
+        let flag = args
+            .iter()
+            .position(|arg| arg == "--input")
+            .and_then(|i| args.get(i + 1));
+
+        Self::resolve(
+            year,
+            flag.map(String::as_str),
+            env::var_os("AOC_INPUT_DIR").map(PathBuf::from),
+        )
+    }
+
+    fn resolve(year: u16, flag: Option<&str>, dir: Option<PathBuf>) -> Self {
+        match (flag, dir) {
+            (Some("-"), _) => InputSource::Stdin,
+            (Some(path), _) if Path::new(path).is_dir() => InputSource::Dir(path.into()),
+            (Some(path), _) => InputSource::File(path.into()),
+            (None, Some(dir)) => InputSource::Dir(dir),
+            (None, None) => InputSource::Dir(inputs_dir(year)),
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, Error> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| Error::MissingInput {
+                        path: "<stdin>".into(),
+                        source,
+                    })?;
+                Ok(input)
             }
+            InputSource::Dir(dir) => read_path(dir.join(format!("{:02}.txt", day))),
+            InputSource::File(path) => read_path(path.clone()),
         }
-    })
+    }
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+/// Read the input of `day` from wherever [`InputSource::from_env`] points.
+pub fn read_input(year: u16, day: u8) -> Result<String, Error> {
+    InputSource::from_env(year).read(day)
 }
 
 #[cfg(test)]
@@ -96,30 +406,145 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
+    fn test_part_result_json() {
+        let result = run_part(2022, 7, 2, |input: &str| input.parse::<u32>().ok(), "42");
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(json.starts_with(r#"{"year":2022,"day":7,"part":2,"answer":"42","elapsed_ns":"#));
+        assert!(json.ends_with(r#","status":"solved"}"#));
+        assert_eq!(serde_json::from_str::<PartResult>(&json).unwrap(), result);
+
+        let result = run_part(2022, 7, 1, |_| None::<u32>, "");
+        assert_eq!(result.answer, None);
+        assert_eq!(result.status, Status::Unsolved);
+        assert!(serde_json::to_string(&result)
+            .unwrap()
+            .contains(r#""answer":null"#));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_cpu_time() {
+        let wait = Duration::from_millis(20);
+        // a busy part is on the CPU the whole time, a sleeping one hardly at all
+        let busy = run_part(
+            2022,
+            1,
+            1,
+            |_| {
+                let started = Instant::now();
+                while started.elapsed() < wait {}
+                Some(1)
+            },
+            "",
+        );
+        let sleeping = run_part(
+            2022,
+            1,
+            2,
+            |_| {
+                std::thread::sleep(wait);
+                Some(1)
+            },
+            "",
         );
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
+        assert!(busy.cpu_time() >= wait / 2);
+        assert!(sleeping.elapsed() >= wait);
+        assert!(sleeping.cpu_time() < wait / 2);
+        assert!(serde_json::to_string(&busy)
+            .unwrap()
+            .contains(r#""cpu_ns":"#));
+    }
+
+    #[test]
+    fn test_input_source() {
+        let dir = env::temp_dir();
+        let file = dir.join("aoc-input-source-test.txt");
+        fs::write(&file, "1\n2\n").unwrap();
+
+        assert_eq!(
+            InputSource::resolve(2022, Some("-"), Some(dir.clone())),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::resolve(2022, dir.to_str(), None),
+            InputSource::Dir(dir.clone())
+        );
+        assert_eq!(
+            InputSource::resolve(2022, None, Some("elsewhere".into())),
+            InputSource::Dir("elsewhere".into())
         );
+        assert_eq!(
+            InputSource::resolve(2021, None, None),
+            InputSource::Dir(input_path("inputs", 2021, 3).parent().unwrap().into())
+        );
+
+        let source = InputSource::resolve(2022, file.to_str(), Some(dir));
+        assert_eq!(source, InputSource::File(file.clone()));
+        assert_eq!(source.read(3).unwrap(), "1\n2\n");
+        fs::remove_file(file).unwrap();
+    }
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
+    #[test]
+    fn test_flat_inputs_fallback() {
+        let flat = env::temp_dir().join("aoc-flat-inputs-test");
+        let _ = fs::remove_dir_all(&flat);
+        fs::create_dir_all(&flat).unwrap();
+        let dir = flat.join(DEFAULT_YEAR.to_string());
+
+        assert_eq!(with_flat_fallback(dir.clone(), DEFAULT_YEAR), dir);
+        fs::write(flat.join("01.txt"), "").unwrap();
+        assert_eq!(with_flat_fallback(dir.clone(), DEFAULT_YEAR), flat);
+        // only the default year had flat inputs
+        let other = flat.join("2021");
+        assert_eq!(with_flat_fallback(other.clone(), 2021), other);
+        fs::create_dir(&dir).unwrap();
+        assert_eq!(with_flat_fallback(dir.clone(), DEFAULT_YEAR), dir);
+
+        fs::remove_dir_all(flat).unwrap();
+    }
+
+    #[test]
+    fn test_part_failures() {
+        let result = run_part(
+            2022,
+            1,
+            1,
+            |_| Err::<u32, _>(Error::parse(3, 14, "expected a number")),
+            "",
+        );
+        assert_eq!(result.status, Status::Failed);
+        assert_eq!(
+            result.error.as_deref(),
+            Some("parse error at 3:14: expected a number")
+        );
+        assert_eq!(
+            Error::parse_at("ab\ncd", 4, "x").to_string(),
+            "parse error at 2:2: x"
+        );
+        assert_eq!(
+            Error::parse(1, 3, "x").on_line(5).to_string(),
+            "parse error at 5:3: x"
         );
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
+        let result = run_part(
+            2022,
+            1,
+            2,
+            |input: &str| Some(input.parse::<u32>().unwrap()),
+            "x",
         );
+        assert_eq!(result.status, Status::Failed);
+        assert!(result
+            .error
+            .unwrap()
+            .starts_with("solver panicked: called `Result::unwrap()`"));
+
+        let error = read_file("nonexistent", 2022, 1).unwrap_err();
+        let result = PartResult::failed(2022, 1, 1, &error);
+        assert_eq!(result.status, Status::Failed);
+        assert!(serde_json::to_string(&result)
+            .unwrap()
+            .contains(r#""status":"failed","error":"could not read"#));
     }
 }
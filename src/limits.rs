@@ -0,0 +1,182 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::Error;
+use std::io::Read;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+use std::{fs, thread};
+
+/// How often a limited process is checked on.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Limits for running a single part. A part with any limit runs in a process of its own,
+/// so that it can be stopped without taking the rest of the run down with it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// Wall-clock time a part may take, for each time it runs.
+    pub timeout: Option<Duration>,
+    /// Resident memory a part may use, in bytes. Only enforced on Linux.
+    pub max_memory: Option<u64>,
+}
+
+impl Limits {
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn max_memory(mut self, bytes: u64) -> Self {
+        self.max_memory = Some(bytes);
+        self
+    }
+
+    pub fn is_unlimited(&self) -> bool {
+        self.timeout.is_none() && self.max_memory.is_none()
+    }
+
+    /// These limits for a process that runs a part `runs` times, e.g. with `--bench`.
+    /// The timeout is multiplied, the memory limit stays as it is.
+    pub fn for_runs(self, runs: u32) -> Limits {
+        Limits {
+            timeout: self.timeout.map(|timeout| timeout * runs.max(1)),
+            ..self
+        }
+    }
+
+    /// These limits, with the ones that aren't set taken from `defaults`.
+    pub fn or(self, defaults: Limits) -> Limits {
+        Limits {
+            timeout: self.timeout.or(defaults.timeout),
+            max_memory: self.max_memory.or(defaults.max_memory),
+        }
+    }
+}
+
+/// Resident memory of a running process in bytes, if the platform tells.
+pub fn resident_memory(pid: u32) -> Option<u64> {
+    let status = fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    let kb = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(kb * 1024)
+}
+
+/// Run `command` to completion and return what it wrote to stdout. The process is
+/// killed as soon as it exceeds one of the `limits`.
+pub fn run_limited(command: &mut Command, limits: Limits) -> Result<String, Error> {
+    let started = Instant::now();
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| Error::Crashed(format!("could not start: {}", e)))?;
+
+    // read while waiting, a solver that prints a lot would block on a full pipe otherwise
+    let mut stdout = child.stdout.take().unwrap();
+    let reader = thread::spawn(move || {
+        let mut output = String::new();
+        stdout.read_to_string(&mut output).map(|_| output)
+    });
+
+    let exceeded = loop {
+        match child.try_wait() {
+            Ok(Some(_)) => break None,
+            Ok(None) => {}
+            Err(e) => break Some(Error::Crashed(e.to_string())),
+        }
+
+        if let Some(timeout) = limits.timeout.filter(|&t| started.elapsed() > t) {
+            break Some(Error::Timeout(timeout));
+        }
+        if let Some(limit) = limits
+            .max_memory
+            .filter(|&limit| resident_memory(child.id()).is_some_and(|used| used > limit))
+        {
+            break Some(Error::OutOfMemory { limit });
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    };
+
+    if let Some(error) = exceeded {
+        let _ = child.kill();
+        let _ = child.wait();
+        return Err(error);
+    }
+
+    let status = child.wait().map_err(|e| Error::Crashed(e.to_string()))?;
+    let output = reader
+        .join()
+        .map_err(Error::from_panic)?
+        .map_err(|e| Error::Crashed(e.to_string()))?;
+
+    if status.success() {
+        Ok(output)
+    } else {
+        Err(Error::Crashed(status.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limits() {
+        let defaults = Limits::default().timeout(Duration::from_secs(10));
+        let limits = Limits::default().max_memory(1024).or(defaults);
+        assert_eq!(limits.timeout, Some(Duration::from_secs(10)));
+        assert_eq!(limits.max_memory, Some(1024));
+        assert!(Limits::default().is_unlimited());
+        assert!(!limits.is_unlimited());
+        assert_eq!(
+            Error::OutOfMemory { limit: 1 }.to_string(),
+            "OOM, used more than 1 B"
+        );
+        assert_eq!(
+            Error::OutOfMemory { limit: 1536 << 20 }.to_string(),
+            "OOM, used more than 1.5 GiB"
+        );
+
+        let runs = limits.for_runs(12);
+        assert_eq!(runs.timeout, Some(Duration::from_secs(120)));
+        assert_eq!(runs.max_memory, Some(1024));
+        assert_eq!(Limits::default().for_runs(12), Limits::default());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_limited() {
+        let sh = |script: &str| {
+            let mut command = Command::new("sh");
+            command.args(["-c", script]);
+            command
+        };
+        let limits = Limits::default().timeout(Duration::from_millis(200));
+
+        assert_eq!(run_limited(&mut sh("echo 42"), limits).unwrap(), "42\n");
+        assert!(matches!(
+            run_limited(&mut sh("sleep 5"), limits),
+            Err(Error::Timeout(_))
+        ));
+        assert!(matches!(
+            run_limited(&mut sh("exit 3"), limits),
+            Err(Error::Crashed(_))
+        ));
+
+        if resident_memory(std::process::id()).is_some() {
+            let limits = limits.max_memory(1);
+            assert!(matches!(
+                run_limited(&mut sh("sleep 5"), limits),
+                Err(Error::OutOfMemory { limit: 1 })
+            ));
+        }
+    }
+}
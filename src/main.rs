@@ -2,47 +2,426 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+// unstable features used by the individual solutions
+#![feature(binary_heap_into_iter_sorted)]
+#![feature(iter_array_chunks)]
+#![feature(iter_intersperse)]
+
+use advent_of_code::answers::{self, Manifest};
+use advent_of_code::baseline::{self, Baseline};
+use advent_of_code::limits::{self, Limits};
+use advent_of_code::runner::{self, Solution, Stats, Summary, STAR_THRESHOLD};
+use advent_of_code::{
+    Error, InputSource, OutputMode, PartResult, Status, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use serde::Serialize;
+use std::env;
+use std::io;
+use std::path::PathBuf;
+use std::process::{self, Command};
+use std::time::{Duration, Instant};
+
+mod days;
+
+struct Args {
+    /// Only the days of this year are run.
+    year: u16,
+    /// Run each part this many times and report timing statistics.
+    bench: Option<usize>,
+    /// Store the timings of this run as the new baseline.
+    save_baseline: bool,
+    /// Compare the timings of this run against the stored baseline.
+    compare: bool,
+    /// Relative slowdown (in percent) that counts as a regression.
+    threshold: f64,
+    baseline_path: PathBuf,
+    /// Profile in `answers.toml` to check the answers against.
+    profile: String,
+    /// Number of days to run at the same time.
+    jobs: usize,
+    /// Days to run, e.g. `1-10,15`. All days if not given.
+    days: Option<Vec<u8>>,
+    /// Run only this part of each day.
+    part: Option<u8>,
+    /// Only print parts that take longer than [`STAR_THRESHOLD`].
+    only_slow: bool,
+    /// Only print parts that weren't solved.
+    only_unsolved: bool,
+    /// Limits for every part, unless its day sets its own.
+    limits: Limits,
+    /// Run the selected parts in this process and print their results for the parent
+    /// process to read, see [`run_in_worker`].
+    worker: bool,
+}
+
+fn parse_timeout(seconds: &str) -> Result<Duration, String> {
+    let seconds = seconds.parse::<f64>().map_err(|e| e.to_string())?;
+    Duration::try_from_secs_f64(seconds).map_err(|e| e.to_string())
+}
+
+fn parse_part(part: &str) -> Result<u8, String> {
+    match part.parse() {
+        Ok(part @ 1..=2) => Ok(part),
+        _ => Err(format!("{:?} is not a part, use 1 or 2", part)),
+    }
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = args
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or_else(advent_of_code::default_year);
+    Ok(Args {
+        year,
+        bench: args.opt_value_from_str("--bench")?,
+        save_baseline: args.contains("--save-baseline"),
+        compare: args.contains("--compare"),
+        threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10_f64),
+        baseline_path: args
+            .opt_value_from_str("--baseline")?
+            .unwrap_or_else(|| baseline::default_path(year)),
+        profile: args
+            .opt_value_from_str(["-p", "--profile"])?
+            .unwrap_or_else(|| answers::DEFAULT_PROFILE.to_string()),
+        jobs: args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1),
+        days: args.opt_value_from_fn("--days", runner::parse_days)?,
+        part: args.opt_value_from_fn("--part", parse_part)?,
+        only_slow: args.contains("--only-slow"),
+        only_unsolved: args.contains("--only-unsolved"),
+        limits: Limits {
+            timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
+            max_memory: args
+                .opt_value_from_str::<_, u64>("--max-memory")?
+                .map(|mib| mib * 1024 * 1024),
+        },
+        worker: args.contains("--worker"),
+    })
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
 
-            let mut args = vec!["run", "--bin", &day];
-            if cfg!(not(debug_assertions)) {
-                args.push("--release");
-            }
+    // every day reads its own file, so `--input` has to name a directory
+    let source = InputSource::from_env(args.year);
+    if !matches!(source, InputSource::Dir(_)) {
+        eprintln!("`--input` must be a directory with one NN.txt file per day.");
+        process::exit(1);
+    }
+
+    // without known answers, every result is simply marked as unknown
+    let manifest = match Manifest::load(answers::MANIFEST_PATH) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            eprintln!("Failed to load \"{}\": {}", answers::MANIFEST_PATH, e);
+            Manifest::default()
+        }
+    };
+
+    let mode = advent_of_code::output_mode();
+    let mut results = Vec::new();
+    // the parts that pass `--only-slow` and `--only-unsolved`, which the total and summary cover
+    let mut shown = Vec::new();
 
-            let cmd = Command::new("cargo").args(&args).output().unwrap();
+    let solutions = days::all()
+        .into_iter()
+        .filter(|solution| solution.year() == args.year)
+        .filter(|solution| {
+            args.days
+                .as_ref()
+                .is_none_or(|days| days.contains(&solution.day()))
+        })
+        .collect::<Vec<_>>();
+    if solutions.is_empty() {
+        eprintln!(
+            "There are no solutions for the selected days of {} in `src/days.rs`.",
+            args.year
+        );
+        process::exit(1);
+    }
 
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
+    if args.worker {
+        let parts = solutions
+            .iter()
+            .flat_map(|solution| run_day(solution.as_ref(), &source, &manifest, &args))
+            .collect::<Vec<_>>();
+        println!("{}", serde_json::to_string(&parts).unwrap());
+        return;
+    }
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+    // days run concurrently, but are printed in order once they are done
+    let started = Instant::now();
+    runner::run_in_order(
+        &solutions,
+        args.jobs,
+        |solution| run_day(solution.as_ref(), &source, &manifest, &args),
+        |parts| {
+            let any_shown = parts
+                .iter()
+                .any(|(result, stats)| is_shown(result, stats.as_ref(), &args));
+            if mode == OutputMode::Human && any_shown {
+                println!("----------");
+                println!("{}| Day {:02} |{}", ANSI_BOLD, parts[0].0.day, ANSI_RESET);
+                println!("----------");
+            }
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+            for (result, stats) in parts {
+                if is_shown(&result, stats.as_ref(), &args) {
+                    match stats {
+                        Some(stats) => print_stats(&result, &stats, mode),
+                        None => advent_of_code::print_result(&result, mode),
+                    }
+                    shown.push(result.clone());
                 }
-            );
+                results.push(result);
+            }
+        },
+    );
+    let wall_clock = started.elapsed();
+
+    // CPU time, so that parts waiting for a free CPU with several jobs don't add up
+    let total: Duration = shown.iter().map(|r| r.cpu_time()).sum();
+
+    if mode == OutputMode::Human {
+        println!(
+            "{}Total:{} {}{:.2}ms{}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total.as_secs_f64() * 1000_f64,
+            ANSI_RESET
+        );
+        // includes reading the inputs and the time parts spend waiting for a free CPU
+        println!(
+            "{}Wall clock:{} {}{:.2}ms ({} {}){}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            wall_clock.as_secs_f64() * 1000_f64,
+            args.jobs.max(1),
+            if args.jobs > 1 { "jobs" } else { "job" },
+            ANSI_RESET
+        );
+
+        println!();
+        println!("{}", Summary::from_results(&shown));
+    }
+
+    if args.compare && !compare_with_baseline(&results, &args) {
+        process::exit(1);
+    }
+
+    if args.save_baseline {
+        // only replace the parts that were measured in this run
+        let mut baseline = match Baseline::load(&args.baseline_path) {
+            Ok(baseline) => baseline,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Baseline::default(),
+            Err(e) => {
+                eprintln!(
+                    "Failed to load baseline \"{}\": {}",
+                    args.baseline_path.display(),
+                    e
+                );
+                process::exit(1);
+            }
+        };
+        baseline.merge(&results);
+
+        match baseline.save(&args.baseline_path) {
+            Ok(_) => eprintln!("Saved baseline to \"{}\".", args.baseline_path.display()),
+            Err(e) => {
+                eprintln!("Failed to save baseline: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Whether a part passes the `--only-slow` and `--only-unsolved` filters.
+fn is_shown(result: &PartResult, stats: Option<&Stats>, args: &Args) -> bool {
+    let slow = match stats {
+        Some(stats) => !stats.is_fast(),
+        None => result.elapsed() >= STAR_THRESHOLD,
+    };
+    (!args.only_slow || slow) && (!args.only_unsolved || result.status != Status::Solved)
+}
+
+/// Run the selected parts of a day, without printing anything.
+/// Parts with limits run in a process of their own.
+fn run_day(
+    solution: &dyn Solution,
+    source: &InputSource,
+    manifest: &Manifest,
+    args: &Args,
+) -> Vec<(PartResult, Option<Stats>)> {
+    let day = solution.day();
+    let mut limits = solution.limits().or(args.limits);
+    // a worker runs every warm-up and iteration of a benchmarked part
+    if let Some(iterations) = args.bench {
+        limits = limits.for_runs(runner::bench_runs(iterations) as u32);
+    }
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+    let parts = if args.worker || limits.is_unlimited() {
+        run_parts(solution, source, args)
+    } else {
+        parts(args)
+            .map(|part| {
+                run_in_worker(day, part, source, limits, args)
+                    .unwrap_or_else(|e| (PartResult::failed(solution.year(), day, part, &e), None))
+            })
+            .collect()
+    };
+
+    parts
+        .into_iter()
+        .map(|(mut result, stats)| {
+            result.verdict = result
+                .answer
+                .as_deref()
+                .map(|answer| manifest.verify(&args.profile, args.year, day, result.part, answer));
+            (result, stats)
+        })
+        .collect()
+}
+
+fn run_parts(
+    solution: &dyn Solution,
+    source: &InputSource,
+    args: &Args,
+) -> Vec<(PartResult, Option<Stats>)> {
+    let day = solution.day();
+
+    let input = match source.read(day) {
+        Ok(input) => input,
+        // report both parts as failed and move on to the next day
+        Err(e) => {
+            return parts(args)
+                .map(|part| (PartResult::failed(solution.year(), day, part, &e), None))
+                .collect()
+        }
+    };
+
+    parts(args)
+        .map(|part| match args.bench {
+            Some(iterations) => {
+                let (result, stats) = solution.bench(part, &input, iterations);
+                (result, Some(stats))
             }
+            None => (solution.run(part, &input), None),
         })
-        .sum();
+        .collect()
+}
+
+/// Run one part in a new process of this binary, which is stopped once it exceeds `limits`.
+fn run_in_worker(
+    day: u8,
+    part: u8,
+    source: &InputSource,
+    limits: Limits,
+    args: &Args,
+) -> Result<(PartResult, Option<Stats>), Error> {
+    let exe = env::current_exe().map_err(|e| Error::Crashed(e.to_string()))?;
+    let mut command = Command::new(exe);
+    command.arg("--worker");
+    command.args(["--year", &args.year.to_string()]);
+    command.args(["--days", &day.to_string(), "--part", &part.to_string()]);
+    if let InputSource::Dir(dir) = source {
+        command.arg("--input").arg(dir);
+    }
+    if let Some(iterations) = args.bench {
+        command.args(["--bench", &iterations.to_string()]);
+    }
+
+    // the solver may have printed something itself, the results come last
+    let output = limits::run_limited(&mut command, limits)?;
+    output
+        .lines()
+        .last()
+        .and_then(|line| serde_json::from_str::<Vec<(PartResult, Option<Stats>)>>(line).ok())
+        .and_then(|parts| parts.into_iter().next())
+        .ok_or_else(|| Error::Crashed("the worker process returned no result".to_string()))
+}
+
+fn parts(args: &Args) -> impl Iterator<Item = u8> {
+    match args.part {
+        Some(part) => part..=part,
+        None => 1..=2,
+    }
+}
+
+/// Print how each part's timing changed. Returns `false` if any part regressed.
+fn compare_with_baseline(results: &[PartResult], args: &Args) -> bool {
+    let baseline = match Baseline::load(&args.baseline_path) {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!(
+                "Failed to load baseline \"{}\": {}. Run with `--save-baseline` first.",
+                args.baseline_path.display(),
+                e
+            );
+            return false;
+        }
+    };
+
+    let comparisons = baseline.compare(results, args.threshold / 100_f64);
+    let regressions = comparisons.iter().filter(|c| c.regressed).count();
+
+    // keep stdout clean for `--json`
+    eprintln!("----------");
+    eprintln!("{}| Baseline |{}", ANSI_BOLD, ANSI_RESET);
+    eprintln!("----------");
+    for comparison in comparisons.iter() {
+        eprintln!("{}", comparison);
+    }
+    eprintln!(
+        "{} of {} parts regressed by more than {}%.",
+        regressions,
+        comparisons.len(),
+        args.threshold
+    );
+
+    regressions == 0
+}
+
+/// A benchmarked part in `json` output mode, i.e. its [`PartResult`] with the [`Stats`] added.
+#[derive(Serialize)]
+struct BenchRecord<'a> {
+    #[serde(flatten)]
+    result: &'a PartResult,
+    stats: &'a Stats,
+}
+
+fn print_stats(result: &PartResult, stats: &Stats, mode: OutputMode) {
+    if mode == OutputMode::Json {
+        let record = BenchRecord { result, stats };
+        println!("{}", serde_json::to_string(&record).unwrap());
+        return;
+    }
+    if result.answer.is_none() {
+        advent_of_code::print_result(result, mode);
+        return;
+    }
 
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, result.part, ANSI_RESET);
     println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+        "{} {}({}{}){} {}{}",
+        result.answer.as_deref().unwrap_or_default(),
+        ANSI_ITALIC,
+        stats,
+        result
+            .alloc
+            .map(|alloc| format!(", {}", alloc))
+            .unwrap_or_default(),
+        ANSI_RESET,
+        if stats.is_fast() { "⭐" } else { "✨" },
+        result
+            .verdict
+            .map(|v| format!(" {}", v))
+            .unwrap_or_default()
     );
 }
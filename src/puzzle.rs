@@ -0,0 +1,83 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref ARTICLE: Regex =
+        Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+    static ref PRE_CODE: Regex = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    static ref EM_CODE: Regex =
+        Regex::new(r"(?s)<code><em>(.*?)</em></code>|<em><code>(.*?)</code></em>").unwrap();
+    static ref TAG: Regex = Regex::new(r"<[^>]*>").unwrap();
+}
+
+/// Strip tags such as the `<em>` highlights inside examples and decode HTML entities.
+fn to_text(html: &str) -> String {
+    TAG.replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The first `<pre><code>` block of a puzzle description, which usually is the example input.
+pub fn example_input(html: &str) -> Option<String> {
+    PRE_CODE
+        .captures(html)
+        .map(|captures| to_text(&captures[1]))
+}
+
+/// The example answers for both parts. Each part of the puzzle is its own `<article>`,
+/// which conventionally ends with the answer for the example as emphasized code.
+pub fn example_answers(html: &str) -> [Option<String>; 2] {
+    let mut articles = ARTICLE.captures_iter(html).map(|article| {
+        EM_CODE
+            .captures_iter(&article[1])
+            .last()
+            .and_then(|c| c.get(1).or_else(|| c.get(2)))
+            .map(|answer| to_text(answer.as_str()))
+    });
+
+    [articles.next().flatten(), articles.next().flatten()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2>
+<p>For example, suppose the Elves finish writing their items' <em>Calories</em>:</p>
+<pre><code>1000
+2000
+
+<em>4000</em> &lt;- &amp;
+</code></pre>
+<p>The fourth Elf is carrying <code>24000</code> Calories, <code><em>24000</em></code> in total.</p>
+</article>
+<p>Your puzzle answer was <code>69693</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>The sum is <em><code>45000</code></em>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_extract_example() {
+        assert_eq!(
+            example_input(PUZZLE).as_deref(),
+            Some("1000\n2000\n\n4000 <- &\n")
+        );
+        assert_eq!(
+            example_answers(PUZZLE),
+            [Some("24000".to_string()), Some("45000".to_string())]
+        );
+
+        let part_one_only = &PUZZLE[..PUZZLE.find("<p>Your puzzle").unwrap()];
+        assert_eq!(example_answers(part_one_only)[1], None);
+        assert_eq!(example_input("<p>nothing</p>"), None);
+    }
+}
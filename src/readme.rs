@@ -0,0 +1,190 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::runner::Stats;
+use std::collections::HashMap;
+
+pub const TABLE_START: &str = "<!--- readme-table --->";
+pub const TABLE_END: &str = "<!--- readme-table end --->";
+
+/// Table cell for a part: ⭐ if it runs under the threshold, ✨ and its median otherwise.
+pub fn format_cell(stats: &Stats) -> String {
+    if stats.is_fast() {
+        return "⭐".to_string();
+    }
+
+    let ms = stats.median.as_secs_f64() * 1000_f64;
+    if ms < 10_f64 {
+        format!("✨ {:.1} ms", ms)
+    } else if ms < 1000_f64 {
+        format!("✨ {:.0} ms", ms)
+    } else {
+        format!("✨ {:.1} s", ms / 1000_f64)
+    }
+}
+
+fn center(text: &str, width: usize) -> String {
+    let padding = width - text.chars().count();
+    let left = padding / 2;
+    format!(
+        " {}{}{} ",
+        " ".repeat(left),
+        text,
+        " ".repeat(padding - left)
+    )
+}
+
+/// Render the benchmark table, one row of two part cells per day.
+pub fn render_table(year: u16, rows: &[(u8, [String; 2])]) -> String {
+    let days = rows
+        .iter()
+        .map(|(day, _)| {
+            format!(
+                "[Day {}](https://adventofcode.com/{}/day/{})",
+                day, year, day
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let day_width = days.iter().map(|d| d.chars().count()).max().unwrap_or(3);
+    let part_width = rows
+        .iter()
+        .flat_map(|(_, cells)| cells.iter().map(|c| c.chars().count()))
+        .max()
+        .unwrap_or(0)
+        .max("Part 1".len());
+
+    let mut table = String::new();
+    let mut push_row = |cells: [String; 3]| {
+        table.push('|');
+        for cell in cells {
+            table.push_str(&cell);
+            table.push('|');
+        }
+        table.push('\n');
+    };
+
+    push_row([
+        center("Day", day_width),
+        center("Part 1", part_width),
+        center("Part 2", part_width),
+    ]);
+    push_row([
+        format!(" :{}: ", "-".repeat(day_width - 2)),
+        format!(" :{}: ", "-".repeat(part_width - 2)),
+        format!(" :{}: ", "-".repeat(part_width - 2)),
+    ]);
+
+    for (day, (_, [one, two])) in days.iter().zip(rows) {
+        push_row([
+            center(day, day_width),
+            center(one, part_width),
+            center(two, part_width),
+        ]);
+    }
+
+    table
+}
+
+/// Part cells of the table currently in the README, by day.
+pub fn parse_table(readme: &str) -> HashMap<u8, [String; 2]> {
+    let mut rows = HashMap::new();
+
+    for line in readme.lines() {
+        let cells = line.split('|').map(str::trim).collect::<Vec<_>>();
+        if cells.len() != 5 {
+            continue;
+        }
+
+        let day = cells[1]
+            .strip_prefix("[Day ")
+            .and_then(|d| d.split(']').next())
+            .and_then(|d| d.parse().ok());
+
+        if let Some(day) = day {
+            rows.insert(day, [cells[2].to_string(), cells[3].to_string()]);
+        }
+    }
+
+    rows
+}
+
+/// Replace everything between the table markers, leaving the rest of the README intact.
+pub fn replace_table(readme: &str, table: &str) -> Option<String> {
+    let start = readme.find(TABLE_START)? + TABLE_START.len();
+    let end = start + readme[start..].find(TABLE_END)?;
+
+    Some(format!("{}\n{}{}", &readme[..start], table, &readme[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn stats(median: Duration) -> Stats {
+        Stats {
+            min: median,
+            median,
+            mean: median,
+            stddev: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_format_cell() {
+        assert_eq!(format_cell(&stats(Duration::from_micros(999))), "⭐");
+        assert_eq!(
+            format_cell(&stats(Duration::from_micros(1100))),
+            "✨ 1.1 ms"
+        );
+        assert_eq!(
+            format_cell(&stats(Duration::from_micros(24_300))),
+            "✨ 24 ms"
+        );
+        assert_eq!(format_cell(&stats(Duration::from_millis(645))), "✨ 645 ms");
+        assert_eq!(format_cell(&stats(Duration::from_millis(2345))), "✨ 2.3 s");
+    }
+
+    #[test]
+    fn test_render_and_replace_table() {
+        let rows = [
+            (9, ["⭐".to_string(), "⭐".to_string()]),
+            (10, ["⭐".to_string(), "✨ 1.1 ms".to_string()]),
+            (11, [String::new(), String::new()]),
+        ];
+        let table = render_table(2022, &rows);
+
+        assert_eq!(
+            table,
+            "\
+|                      Day                       |  Part 1  |  Part 2  |
+| :--------------------------------------------: | :------: | :------: |
+|  [Day 9](https://adventofcode.com/2022/day/9)  |    ⭐     |    ⭐     |
+| [Day 10](https://adventofcode.com/2022/day/10) |    ⭐     | ✨ 1.1 ms |
+| [Day 11](https://adventofcode.com/2022/day/11) |          |          |
+"
+        );
+
+        let readme = format!(
+            "# Title\n\n{}\nold\n{}\n\nMore text.\n",
+            TABLE_START, TABLE_END
+        );
+        let updated = replace_table(&readme, &table).unwrap();
+        assert_eq!(
+            updated,
+            format!(
+                "# Title\n\n{}\n{}{}\n\nMore text.\n",
+                TABLE_START, table, TABLE_END
+            )
+        );
+
+        let parsed = parse_table(&updated);
+        assert_eq!(parsed.len(), 3);
+        assert_eq!(parsed[&10], rows[1].1);
+        assert_eq!(parsed[&11], rows[2].1);
+
+        assert_eq!(replace_table("no markers", &table), None);
+    }
+}
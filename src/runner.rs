@@ -0,0 +1,455 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::limits::Limits;
+use crate::{Error, IntoAnswer, PartResult, Status, ANSI_BOLD, ANSI_RESET};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Parts running longer than this are marked with ✨ instead of ⭐.
+pub const STAR_THRESHOLD: Duration = Duration::from_millis(1);
+
+/// Answer to one part of a puzzle, or why there is none.
+pub type Answer = Result<Box<dyn Display>, Error>;
+
+/// A day of the calendar that can be run in-process by `cargo all`.
+pub trait Solution: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn part_one(&self, input: &str) -> Answer;
+    fn part_two(&self, input: &str) -> Answer;
+
+    /// Limits for each part of this day. Each limit set here takes precedence over the
+    /// one given to the runner, the others are taken from it (see [`Limits::or`]).
+    fn limits(&self) -> Limits {
+        Limits::default()
+    }
+}
+
+/// Adapts the `part_one` and `part_two` functions of a day's module to [`Solution`].
+pub struct Day<A, B> {
+    year: u16,
+    day: u8,
+    part_one: fn(&str) -> A,
+    part_two: fn(&str) -> B,
+    limits: Limits,
+}
+
+impl<A, B> Day<A, B> {
+    pub fn new(year: u16, day: u8, part_one: fn(&str) -> A, part_two: fn(&str) -> B) -> Self {
+        Day {
+            year,
+            day,
+            part_one,
+            part_two,
+            limits: Limits::default(),
+        }
+    }
+
+    /// e.g. a longer timeout for a day that is known to be slow.
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+}
+
+impl<A, B> Solution for Day<A, B>
+where
+    A: IntoAnswer,
+    A::Answer: 'static,
+    B: IntoAnswer,
+    B::Answer: 'static,
+{
+    fn year(&self) -> u16 {
+        self.year
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn part_one(&self, input: &str) -> Answer {
+        (self.part_one)(input)
+            .into_answer()
+            .map(|answer| Box::new(answer) as Box<dyn Display>)
+    }
+
+    fn part_two(&self, input: &str) -> Answer {
+        (self.part_two)(input)
+            .into_answer()
+            .map(|answer| Box::new(answer) as Box<dyn Display>)
+    }
+
+    fn limits(&self) -> Limits {
+        self.limits
+    }
+}
+
+impl dyn Solution + '_ {
+    /// Run part 1 or 2 of this day on `input`.
+    pub fn run(&self, part: u8, input: &str) -> crate::PartResult {
+        match part {
+            1 => crate::run_part(self.year(), self.day(), part, |i| self.part_one(i), input),
+            2 => crate::run_part(self.year(), self.day(), part, |i| self.part_two(i), input),
+            _ => panic!("there are only two parts to a puzzle, got part {}", part),
+        }
+    }
+}
+
+/// Timing statistics over repeated runs of one part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", with = "nanos")]
+    pub mean: Duration,
+    #[serde(rename = "stddev_ns", with = "nanos")]
+    pub stddev: Duration,
+}
+
+/// Durations as whole nanoseconds, like [`PartResult::elapsed_ns`].
+mod nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "need at least one sample");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let mean = sorted.iter().map(|d| d.as_secs_f64()).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+
+    pub fn is_fast(&self) -> bool {
+        self.median < STAR_THRESHOLD
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "min: {:.2?}, median: {:.2?}, mean: {:.2?}, stddev: {:.2?}",
+            self.min, self.median, self.mean, self.stddev
+        )
+    }
+}
+
+impl dyn Solution + '_ {
+    /// Run a part `iterations` times after a few warm-up runs.
+    /// The returned result carries the median time and CPU time.
+    pub fn bench(&self, part: u8, input: &str, iterations: usize) -> (crate::PartResult, Stats) {
+        let iterations = iterations.max(1);
+
+        for _ in 0..warm_ups(iterations) {
+            self.run(part, input);
+        }
+
+        let mut result = self.run(part, input);
+        let mut samples = vec![result.elapsed()];
+        let mut cpu_samples = result
+            .cpu_ns
+            .map(Duration::from_nanos)
+            .into_iter()
+            .collect::<Vec<_>>();
+        for _ in 1..iterations {
+            let run = self.run(part, input);
+            samples.push(run.elapsed());
+            cpu_samples.extend(run.cpu_ns.map(Duration::from_nanos));
+        }
+
+        let stats = Stats::from_samples(&samples);
+        result.elapsed_ns = stats.median.as_nanos() as u64;
+        if !cpu_samples.is_empty() {
+            result.cpu_ns = Some(Stats::from_samples(&cpu_samples).median.as_nanos() as u64);
+        }
+
+        (result, stats)
+    }
+}
+
+/// Untimed runs before `iterations` timed ones in `bench`.
+fn warm_ups(iterations: usize) -> usize {
+    (iterations / 10).max(1)
+}
+
+/// How often `--bench <iterations>` runs each part, warm-ups included.
+pub fn bench_runs(iterations: usize) -> usize {
+    let iterations = iterations.max(1);
+    warm_ups(iterations) + iterations
+}
+
+/// Parse a selection of days such as `1-10,15`. The days are returned sorted and without duplicates.
+pub fn parse_days(spec: &str) -> Result<Vec<u8>, String> {
+    let parse = |day: &str| match day.trim().parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("{:?} is not a day between 1 and 25", day.trim())),
+    };
+
+    let mut days = Vec::new();
+    for range in spec.split(',') {
+        match range.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (parse(first)?, parse(last)?);
+                if first > last {
+                    return Err(format!("{:?} is an empty range", range));
+                }
+                days.extend(first..=last);
+            }
+            None => days.push(parse(range)?),
+        }
+    }
+
+    days.sort_unstable();
+    days.dedup();
+    Ok(days)
+}
+
+/// How many parts of a run ended up in each [`Status`], and which ones weren't solved.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub solved: usize,
+    /// `(day, part)` of every part without an answer.
+    pub unsolved: Vec<(u8, u8)>,
+    pub failed: Vec<(u8, u8)>,
+}
+
+impl Summary {
+    pub fn from_results(results: &[PartResult]) -> Summary {
+        let mut summary = Summary::default();
+        for result in results {
+            let part = (result.day, result.part);
+            match result.status {
+                Status::Solved => summary.solved += 1,
+                Status::Unsolved => summary.unsolved.push(part),
+                Status::Failed => summary.failed.push(part),
+            }
+        }
+        summary
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // e.g. `Unsolved    2  16/1 16/2`
+        let row = |f: &mut fmt::Formatter, status: &str, count: usize, parts: &[(u8, u8)]| {
+            write!(f, "{:<8} {:>5}", status, count)?;
+            for (day, part) in parts {
+                write!(f, " {:02}/{}", day, part)?;
+            }
+            Ok(())
+        };
+
+        writeln!(f, "{}Status   Parts{}", ANSI_BOLD, ANSI_RESET)?;
+        row(f, "Solved", self.solved, &[])?;
+        writeln!(f)?;
+        row(f, "Unsolved", self.unsolved.len(), &self.unsolved)?;
+        writeln!(f)?;
+        row(f, "Failed", self.failed.len(), &self.failed)
+    }
+}
+
+/// Spawned threads get as much stack as the main thread, for solutions that recurse deeply.
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Calls `work` on every item on up to `jobs` threads, and `done` with the results in
+/// the order of `items`. Results that finish early wait for the ones before them.
+/// A single job runs everything on the calling thread.
+pub fn run_in_order<T, R>(
+    items: &[T],
+    jobs: usize,
+    work: impl Fn(&T) -> R + Sync,
+    mut done: impl FnMut(R),
+) where
+    T: Sync,
+    R: Send,
+{
+    if jobs <= 1 {
+        items.iter().map(&work).for_each(done);
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let (next, work, sender) = (&next, &work, sender.clone());
+            thread::Builder::new()
+                .stack_size(STACK_SIZE)
+                .spawn_scoped(scope, move || loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(i) else {
+                        break;
+                    };
+                    if sender.send((i, work(item))).is_err() {
+                        break;
+                    }
+                })
+                .expect("failed to spawn a worker thread");
+        }
+        drop(sender);
+
+        let mut finished = BTreeMap::new();
+        let mut expected = 0;
+        for (i, result) in receiver {
+            finished.insert(i, result);
+            while let Some(result) = finished.remove(&expected) {
+                done(result);
+                expected += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Status;
+
+    fn part_one(input: &str) -> Option<u32> {
+        input.parse().ok()
+    }
+
+    fn part_two(input: &str) -> Result<String, Error> {
+        match input {
+            "42" => Err(Error::Unsolved),
+            _ => Err(Error::parse(1, 1, "not 42")),
+        }
+    }
+
+    #[test]
+    fn test_day_adapter() {
+        let solution: Box<dyn Solution> = Box::new(Day::new(2021, 3, part_one, part_two));
+        assert_eq!((solution.year(), solution.day()), (2021, 3));
+
+        let result = solution.run(1, "42");
+        assert_eq!((result.day, result.part), (3, 1));
+        assert_eq!(result.answer.as_deref(), Some("42"));
+        assert_eq!(result.status, Status::Solved);
+
+        let result = solution.run(2, "42");
+        assert_eq!(result.answer, None);
+        assert_eq!(result.status, Status::Unsolved);
+
+        let result = solution.run(2, "41");
+        assert_eq!(result.status, Status::Failed);
+
+        let (result, stats) = solution.bench(1, "42", 5);
+        assert_eq!(result.answer.as_deref(), Some("42"));
+        assert_eq!(result.elapsed(), stats.median);
+    }
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+
+        // mean and stddev go through `f64`
+        let close = |d: Duration, micros: f64| (d.as_secs_f64() * 1e6 - micros).abs() < 0.01;
+
+        let stats = Stats::from_samples(&[ms(4), ms(1), ms(3), ms(2)]);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert!(close(stats.mean, 2500_f64));
+        assert!(close(stats.stddev, 1118.03));
+        assert!(!stats.is_fast());
+
+        let json = serde_json::to_string(&stats).unwrap();
+        assert!(json.starts_with(r#"{"min_ns":1000000,"median_ns":2500000,"mean_ns":"#));
+        assert_eq!(serde_json::from_str::<Stats>(&json).unwrap(), stats);
+
+        let stats = Stats::from_samples(&[ms(5), Duration::from_micros(10), ms(0)]);
+        assert_eq!(stats.median, Duration::from_micros(10));
+        assert!(stats.is_fast());
+
+        assert_eq!(bench_runs(100), 110);
+        assert_eq!(bench_runs(5), 6);
+        assert_eq!(bench_runs(0), 2);
+    }
+
+    #[test]
+    fn test_run_in_order() {
+        let items = (0..20_u64).collect::<Vec<_>>();
+        for jobs in [1, 4, 50] {
+            let mut seen = Vec::new();
+            run_in_order(
+                &items,
+                jobs,
+                |&i| {
+                    // make early items finish last
+                    thread::sleep(Duration::from_millis(20 - i));
+                    i * 2
+                },
+                |result| seen.push(result),
+            );
+            assert_eq!(seen, items.iter().map(|i| i * 2).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("1-3,15"), Ok(vec![1, 2, 3, 15]));
+        assert_eq!(parse_days("25, 2-3,3"), Ok(vec![2, 3, 25]));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("5-4").is_err());
+        assert!(parse_days("1-").is_err());
+        assert!(parse_days("").is_err());
+    }
+
+    #[test]
+    fn test_summary() {
+        let result = |day, part, status| PartResult {
+            status,
+            ..PartResult::new(2022, day, part, Err(Error::Unsolved), Duration::ZERO)
+        };
+        let summary = Summary::from_results(&[
+            result(1, 1, Status::Solved),
+            result(1, 2, Status::Unsolved),
+            result(2, 1, Status::Solved),
+            result(12, 2, Status::Failed),
+        ]);
+
+        assert_eq!(summary.solved, 2);
+        assert_eq!(summary.unsolved, [(1, 2)]);
+        assert_eq!(summary.failed, [(12, 2)]);
+        assert!(summary.to_string().ends_with("\nFailed       1 12/2"));
+    }
+}
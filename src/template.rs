@@ -0,0 +1,293 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use regex::Regex;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Template for new days. Edit it to change what `cargo scaffold` generates.
+pub const TEMPLATE_PATH: &str = "templates/day.rs";
+
+/// Used when there is no template at [`TEMPLATE_PATH`].
+pub const DEFAULT_TEMPLATE: &str = include_str!("../templates/day.rs");
+
+/// Registry of the days that `cargo all` runs, see [`register`].
+pub const REGISTRY_PATH: &str = "src/days.rs";
+
+/// Return type of `part_one` and `part_two`, wrapped in `Option`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Returns {
+    #[default]
+    U32,
+    U64,
+    I64,
+    String,
+}
+
+impl FromStr for Returns {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "u32" => Ok(Returns::U32),
+            "u64" => Ok(Returns::U64),
+            "i64" => Ok(Returns::I64),
+            "String" => Ok(Returns::String),
+            _ => Err(format!("expected u32, u64, i64 or String, got \"{}\"", s)),
+        }
+    }
+}
+
+impl Display for Returns {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Returns::U32 => write!(f, "u32"),
+            Returns::U64 => write!(f, "u64"),
+            Returns::I64 => write!(f, "i64"),
+            Returns::String => write!(f, "String"),
+        }
+    }
+}
+
+impl Returns {
+    /// `answer` as an expected value of this type, e.g. `Some(42)`.
+    pub fn literal(&self, answer: &str) -> Option<String> {
+        let valid = match self {
+            Returns::U32 => answer.parse::<u32>().is_ok(),
+            Returns::U64 => answer.parse::<u64>().is_ok(),
+            Returns::I64 => answer.parse::<i64>().is_ok(),
+            Returns::String => return Some(format!("Some({:?}.to_string())", answer)),
+        };
+        valid.then(|| format!("Some({})", answer))
+    }
+
+    /// Type of the fields parsed by `--regex`. Strings can't be parsed by `parse_with_regex`.
+    fn field(&self) -> Returns {
+        match self {
+            Returns::String => Returns::U32,
+            other => *other,
+        }
+    }
+}
+
+/// Everything that goes into the placeholders of a template.
+#[derive(Debug, Clone, Default)]
+pub struct Module {
+    pub day: u8,
+    pub year: u16,
+    pub returns: Returns,
+    /// Pattern for a `lazy_static!` regex with a `parse_line` function using it.
+    pub regex: Option<Regex>,
+    /// Expected answers for the example, as extracted from the puzzle description.
+    pub examples: [Option<String>; 2],
+}
+
+/// `s` as a raw string literal, with enough `#`s that no `"#…` inside ends it early.
+fn raw_string(s: &str) -> String {
+    let hashes = s
+        .match_indices('"')
+        .map(|(i, _)| s[i + 1..].chars().take_while(|&c| c == '#').count() + 1)
+        .max()
+        .unwrap_or(0);
+    format!("r{h}\"{s}\"{h}", h = "#".repeat(hashes), s = s)
+}
+
+impl Module {
+    fn parser(&self) -> String {
+        let Some(re) = &self.regex else {
+            return String::new();
+        };
+        let (pattern, fields) = (re.as_str(), re.captures_len() - 1);
+
+        format!(
+            r#"use advent_of_code::{{helpers::parse_with_regex, Error}};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {{
+    static ref RE: Regex = Regex::new({pattern}).unwrap();
+}}
+
+fn parse_line(line: &str) -> Result<[{ty}; {fields}], Error> {{
+    parse_with_regex::<{ty}, {fields}>(&RE, line)
+}}
+"#,
+            pattern = raw_string(pattern),
+            ty = self.returns.field(),
+            fields = fields,
+        )
+    }
+
+    /// Substitutes `{{day}}`, `{{year}}`, `{{returns}}`, `{{parser}}`, `{{preload}}`,
+    /// `{{example_one}}` and `{{example_two}}`. Lines with only an empty placeholder are dropped.
+    pub fn render(&self, template: &str) -> String {
+        let examples = self.examples.clone().map(|example| {
+            example
+                .and_then(|answer| self.returns.literal(&answer))
+                .unwrap_or_else(|| "None".to_string())
+        });
+        let preload = match self.regex {
+            Some(_) => r#"let _ = RE.is_match(""); // pre-load regex"#,
+            None => "",
+        };
+
+        let placeholders = [
+            ("{{day}}", self.day.to_string()),
+            ("{{year}}", self.year.to_string()),
+            ("{{returns}}", self.returns.to_string()),
+            ("{{parser}}", self.parser()),
+            ("{{preload}}", preload.to_string()),
+            ("{{example_one}}", examples[0].clone()),
+            ("{{example_two}}", examples[1].clone()),
+        ];
+
+        let mut rendered = String::new();
+        for line in template.split_inclusive('\n') {
+            let empty = placeholders
+                .iter()
+                .any(|(name, value)| value.is_empty() && line.trim() == *name);
+            if empty {
+                continue;
+            }
+
+            let mut line = line.to_string();
+            for (name, value) in placeholders.iter() {
+                line = line.replace(name, value);
+            }
+            rendered.push_str(&line);
+        }
+        rendered
+    }
+}
+
+/// Adds the module of a day and its `Day::new` line to the source of the registry at
+/// [`REGISTRY_PATH`], after the days that are already there.
+pub fn register(registry: &str, year: u16, day: u8) -> Result<String, String> {
+    let module = format!("y{}_d{:02}", year, day);
+    if registry.contains(&format!("mod {};", module)) {
+        return Err(format!("{} is already registered", module));
+    }
+
+    // after the last module, and at the end of the list returned by `all`
+    let modules_end = registry
+        .rfind("\nmod ")
+        .and_then(|start| Some(start + registry[start + 1..].find('\n')? + 2))
+        .ok_or("there are no day modules to add it to")?;
+    let list_end = registry
+        .find("pub fn all()")
+        .and_then(|start| Some(start + registry[start..].find("\n    ]\n")? + 1))
+        .ok_or("there is no `pub fn all()` returning a `vec!` to add it to")?;
+
+    let declaration = format!(
+        "#[path = \"bin/{}.rs\"]\nmod {};\n",
+        crate::bin_name(year, day),
+        module
+    );
+    let entry = format!(
+        "        Box::new(Day::new({}, {}, {m}::part_one, {m}::part_two)),\n",
+        year,
+        day,
+        m = module
+    );
+
+    Ok([
+        &registry[..modules_end],
+        &declaration,
+        &registry[modules_end..list_end],
+        &entry,
+        &registry[list_end..],
+    ]
+    .concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_template() {
+        let module = Module {
+            day: 7,
+            year: 2022,
+            ..Default::default()
+        };
+        let rendered = module.render(DEFAULT_TEMPLATE);
+
+        assert!(rendered
+            .starts_with("#![feature(test)]\n\npub fn part_one(input: &str) -> Option<u32> {"));
+        assert!(
+            rendered.contains("fn main() {\n    let input = &advent_of_code::read_input(2022, 7);")
+        );
+        assert!(rendered.contains("assert_eq!(part_two(&input), None);"));
+        assert!(!rendered.contains("{{"));
+    }
+
+    #[test]
+    fn test_typed_answers_and_regex() {
+        let module = Module {
+            day: 5,
+            year: 2022,
+            returns: "i64".parse().unwrap(),
+            regex: Some(Regex::new(r"^(\d+)-(-?\d+)$").unwrap()),
+            examples: [Some("-3".to_string()), Some("CMZ".to_string())],
+        };
+        let rendered = module.render(DEFAULT_TEMPLATE);
+
+        assert!(rendered
+            .contains("static ref RE: Regex = Regex::new(r\"^(\\d+)-(-?\\d+)$\").unwrap();"));
+        assert!(rendered.contains("fn parse_line(line: &str) -> Result<[i64; 2], Error> {\n    parse_with_regex::<i64, 2>(&RE, line)\n}\n\npub fn part_one(input: &str) -> Option<i64> {"));
+        assert!(
+            rendered.contains("    let _ = RE.is_match(\"\"); // pre-load regex\n    let input")
+        );
+        assert!(rendered.contains("assert_eq!(part_one(&input), Some(-3));"));
+        // not an i64, so left for the user
+        assert!(rendered.contains("assert_eq!(part_two(&input), None);"));
+
+        let returns = "String".parse::<Returns>().unwrap();
+        assert_eq!(returns.literal("CMZ").unwrap(), "Some(\"CMZ\".to_string())");
+        assert!("f32".parse::<Returns>().is_err());
+
+        assert_eq!(raw_string(r"\d+"), r#"r"\d+""#);
+        assert_eq!(raw_string(r#"^"(\w+)"$"#), r##"r#"^"(\w+)"$"#"##);
+        assert_eq!(
+            raw_string(r##"^(\d+)"#(\d+)"##),
+            r###"r##"^(\d+)"#(\d+)"##"###
+        );
+    }
+
+    #[test]
+    fn test_register() {
+        let registry = "use advent_of_code::runner::{Day, Solution};
+
+#[path = \"bin/2022-01.rs\"]
+mod y2022_d01;
+
+pub fn all() -> Vec<Box<dyn Solution>> {
+    vec![
+        Box::new(Day::new(2022, 1, y2022_d01::part_one, y2022_d01::part_two)),
+    ]
+}
+";
+        let registered = register(registry, 2022, 2).unwrap();
+        assert_eq!(
+            registered,
+            "use advent_of_code::runner::{Day, Solution};
+
+#[path = \"bin/2022-01.rs\"]
+mod y2022_d01;
+#[path = \"bin/2022-02.rs\"]
+mod y2022_d02;
+
+pub fn all() -> Vec<Box<dyn Solution>> {
+    vec![
+        Box::new(Day::new(2022, 1, y2022_d01::part_one, y2022_d01::part_two)),
+        Box::new(Day::new(2022, 2, y2022_d02::part_one, y2022_d02::part_two)),
+    ]
+}
+"
+        );
+        assert!(register(&registered, 2022, 2).is_err());
+        assert!(register("", 2022, 2).is_err());
+    }
+}
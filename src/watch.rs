@@ -0,0 +1,140 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// Notices changes to a fixed set of files by comparing their modification times.
+/// A file that doesn't exist yet counts as changed once it appears.
+pub struct Watcher {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+fn modified(path: &PathBuf) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let modified = modified(&path);
+                (path, modified)
+            })
+            .collect();
+        Watcher { files }
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &PathBuf> {
+        self.files.iter().map(|(path, _)| path)
+    }
+
+    /// Files that changed since the last poll.
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+        for (path, last) in self.files.iter_mut() {
+            let now = modified(path);
+            if now != *last {
+                *last = now;
+                changed.push(path.clone());
+            }
+        }
+        changed
+    }
+}
+
+/// Outcome of a `cargo test` run, read from the test harness' output.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TestSummary {
+    pub passed: usize,
+    /// Names of the failed tests, e.g. `tests::test_part_one`.
+    pub failed: Vec<String>,
+}
+
+impl TestSummary {
+    /// `None` if no tests were run at all, e.g. because the build failed.
+    pub fn parse(output: &str) -> Option<Self> {
+        let mut summary = TestSummary::default();
+        let mut finished = false;
+
+        for line in output.lines() {
+            if let Some(counts) = line.strip_prefix("test result: ") {
+                finished = true;
+                summary.passed += counts
+                    .split("; ")
+                    .find_map(|count| count.split_once(". ")?.1.strip_suffix(" passed"))
+                    .and_then(|n| n.parse::<usize>().ok())
+                    .unwrap_or(0);
+            } else if let Some(name) = line
+                .strip_prefix("test ")
+                .and_then(|l| l.strip_suffix(" ... FAILED"))
+            {
+                summary.failed.push(name.to_string());
+            }
+        }
+
+        finished.then_some(summary)
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_watcher() {
+        let path = std::env::temp_dir().join(format!("aoc-watch-{}.txt", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut watcher = Watcher::new(vec![path.clone()]);
+        assert!(watcher.poll().is_empty());
+
+        fs::write(&path, "1").unwrap();
+        assert_eq!(watcher.poll().len(), 1);
+        assert!(watcher.poll().is_empty());
+
+        let later = SystemTime::now() + Duration::from_secs(5);
+        fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        assert_eq!(watcher.poll().len(), 1);
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(watcher.poll(), [path]);
+    }
+
+    #[test]
+    fn test_parse_test_output() {
+        let output = "
+running 2 tests
+test tests::test_part_one ... ok
+test tests::test_part_two ... FAILED
+
+failures:
+
+---- tests::test_part_two stdout ----
+thread 'tests::test_part_two' panicked at src/bin/2022-01.rs:58:9
+
+test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 2 filtered out; finished in 0.00s
+";
+        assert_eq!(
+            TestSummary::parse(output),
+            Some(TestSummary {
+                passed: 1,
+                failed: vec!["tests::test_part_two".to_string()],
+            })
+        );
+        assert!(TestSummary::parse("error[E0308]: mismatched types").is_none());
+    }
+}
@@ -0,0 +1,47 @@
+#![feature(test)]
+
+{{parser}}
+pub fn part_one(input: &str) -> Option<{{returns}}> {
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{returns}}> {
+    None
+}
+
+fn main() {
+    {{preload}}
+    let input = &advent_of_code::read_input({{year}}, {{day}});
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate test;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", {{year}}, {{day}}).unwrap();
+        assert_eq!(part_one(&input), {{example_one}});
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", {{year}}, {{day}}).unwrap();
+        assert_eq!(part_two(&input), {{example_two}});
+    }
+
+    #[bench]
+    fn bench_part_one(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", {{year}}, {{day}}).unwrap();
+        b.iter(|| part_one(input));
+    }
+
+    #[bench]
+    fn bench_part_two(b: &mut test::Bencher) {
+        let input = &advent_of_code::read_file("inputs", {{year}}, {{day}}).unwrap();
+        b.iter(|| part_two(input));
+    }
+}
@@ -0,0 +1,154 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+// unstable features used by the individual solutions
+#![feature(binary_heap_into_iter_sorted)]
+#![feature(iter_array_chunks)]
+#![feature(iter_intersperse)]
+// the days' own tests and benches get compiled in as well, they are just never run
+#![feature(test)]
+
+//! Checks every answer in `answers.toml` against the inputs of its profile.
+//! Run with `cargo test --test answers`, optionally followed by `-- <filter>`
+//! to only check e.g. `alice` or `default/2022/05`.
+
+use advent_of_code::answers::{self, Manifest};
+use advent_of_code::{InputSource, ANSI_GREEN, ANSI_RED, ANSI_RESET};
+use std::collections::HashMap;
+use std::path::Path;
+use std::{fs, process};
+
+#[path = "../src/days.rs"]
+mod days;
+
+fn main() {
+    let manifest = match Manifest::load(answers::MANIFEST_PATH) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            eprintln!("Failed to load \"{}\": {}", answers::MANIFEST_PATH, e);
+            process::exit(1);
+        }
+    };
+
+    // libtest flags such as `--nocapture` don't apply here
+    let filter = std::env::args().skip(1).find(|arg| !arg.starts_with('-'));
+    let solutions = days::all()
+        .into_iter()
+        .map(|solution| ((solution.year(), solution.day()), solution))
+        .collect::<HashMap<_, _>>();
+
+    let cases = manifest
+        .cases()
+        .into_iter()
+        .filter(|c| {
+            let name = format!("{}/{}/{:02}", c.profile, c.year, c.day);
+            filter.as_ref().is_none_or(|f| name.contains(f.as_str()))
+        })
+        .collect::<Vec<_>>();
+
+    let check_registry = filter
+        .as_ref()
+        .is_none_or(|f| "registry".contains(f.as_str()));
+
+    let (mut passed, mut failed, mut skipped) = (0, 0, 0);
+    println!(
+        "\nrunning {} checks",
+        cases.len() + usize::from(check_registry)
+    );
+
+    if check_registry {
+        print!("check registry ... ");
+        let missing = unregistered_days(&solutions);
+        if missing.is_empty() {
+            println!("{}ok{}", ANSI_GREEN, ANSI_RESET);
+            passed += 1;
+        } else {
+            println!(
+                "{}FAILED{} (add {:?} to `src/days.rs`)",
+                ANSI_RED, ANSI_RESET, missing
+            );
+            failed += 1;
+        }
+    }
+
+    for case in cases {
+        print!(
+            "check {}/{}/{:02} part {} ... ",
+            case.profile, case.year, case.day, case.part
+        );
+
+        // teammates don't necessarily have everyone's inputs
+        let dir = answers::profile_dir(case.year, case.profile);
+        if !dir.is_dir() {
+            println!("skipped (no \"{}\")", dir.display());
+            skipped += 1;
+            continue;
+        }
+
+        let outcome = match (
+            solutions.get(&(case.year, case.day)),
+            InputSource::Dir(dir).read(case.day),
+        ) {
+            (None, _) => Err(format!(
+                "day {} of {} is not registered",
+                case.day, case.year
+            )),
+            (_, Err(e)) => Err(e.to_string()),
+            (Some(solution), Ok(input)) => {
+                let result = solution.run(case.part, &input);
+                match (result.answer, result.error) {
+                    (Some(answer), _) if answer == case.expected => Ok(()),
+                    (Some(answer), _) => {
+                        Err(format!("expected {:?}, got {:?}", case.expected, answer))
+                    }
+                    (None, Some(error)) => Err(error),
+                    (None, None) => Err("not solved".to_string()),
+                }
+            }
+        };
+
+        match outcome {
+            Ok(()) => {
+                println!("{}ok{}", ANSI_GREEN, ANSI_RESET);
+                passed += 1;
+            }
+            Err(reason) => {
+                println!("{}FAILED{} ({})", ANSI_RED, ANSI_RESET, reason);
+                failed += 1;
+            }
+        }
+    }
+
+    println!(
+        "\ncheck result: {}. {} passed; {} failed; {} skipped\n",
+        if failed == 0 { "ok" } else { "FAILED" },
+        passed,
+        failed,
+        skipped
+    );
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+/// Day binaries in `src/bin` that `cargo all` doesn't know about.
+fn unregistered_days<T>(solutions: &HashMap<(u16, u8), T>) -> Vec<String> {
+    let bin = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("bin");
+    let mut missing = fs::read_dir(bin)
+        .expect("could not list src/bin")
+        .filter_map(|entry| Some(entry.ok()?.path().file_stem()?.to_str()?.to_string()))
+        .filter(|name| {
+            // day binaries are named `<year>-<day>`, the others have names
+            let day = name
+                .split_once('-')
+                .and_then(|(year, day)| Some((year.parse().ok()?, day.parse().ok()?)));
+            day.is_some_and(|day| !solutions.contains_key(&day))
+        })
+        .collect::<Vec<_>>();
+    missing.sort();
+    missing
+}